@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use tommaso_date_formatter::{validate, Format, ParseOptions};
+
+fn tokenize(c: &mut Criterion) {
+    let long = "yyyymmdd ".repeat(100);
+    c.bench_function("parse a long pattern", |b| {
+        b.iter(|| Format::parse(black_box(&long)))
+    });
+    let options = ParseOptions {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    let upper = long.to_uppercase();
+    c.bench_function("parse a long pattern ignoring case", |b| {
+        b.iter(|| Format::parse_with(black_box(&upper), &options))
+    });
+}
+
+fn realistic(c: &mut Criterion) {
+    let pattern = "dddd, do mmmm yyyy 'at' hh:nn tt";
+    c.bench_function("parse a realistic pattern", |b| {
+        b.iter(|| Format::parse(black_box(pattern)))
+    });
+    c.bench_function("validate a realistic pattern", |b| {
+        b.iter(|| validate(black_box(pattern)))
+    });
+}
+
+criterion_group!(benches, tokenize, realistic);
+criterion_main!(benches);
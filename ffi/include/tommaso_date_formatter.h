@@ -0,0 +1,40 @@
+#ifndef TOMMASO_DATE_FORMATTER_H
+#define TOMMASO_DATE_FORMATTER_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* The date was written to the buffer. */
+#define TDF_OK 0
+/* The pattern or the buffer is a null pointer. */
+#define TDF_NULL_POINTER 1
+/* The pattern is not valid UTF-8. */
+#define TDF_INVALID_UTF8 2
+/* The year, the month and the day don't make a valid date. */
+#define TDF_INVALID_DATE 3
+/* The pattern is not a valid format. */
+#define TDF_INVALID_FORMAT 4
+/* The buffer is too small, so only the start of the date was written. */
+#define TDF_TRUNCATED 5
+
+/*
+ * Writes the date made of `year`, `month` and `day` in the format `pattern`,
+ * a NUL-terminated UTF-8 string, to the buffer `out` of `out_len` bytes as a
+ * NUL-terminated UTF-8 string. Returns TDF_OK or one of the other TDF_ error
+ * codes.
+ *
+ * When the buffer is too small as many whole characters as fit are written,
+ * still followed by a NUL, and TDF_TRUNCATED is returned.
+ */
+int32_t tdf_format(int32_t year, uint32_t month, uint32_t day, const char *pattern,
+                   char *out, size_t out_len);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,7 @@
+//! Builds `tommaso_date_formatter` as a C library, a shared one and a static
+//! one, exporting `tdf_format` as declared in `include/tommaso_date_formatter.h`.
+
+pub use tommaso_date_formatter::{
+    tdf_format, TDF_INVALID_DATE, TDF_INVALID_FORMAT, TDF_INVALID_UTF8, TDF_NULL_POINTER, TDF_OK,
+    TDF_TRUNCATED,
+};
@@ -0,0 +1,55 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, LitStr};
+use tommaso_date_formatter::{Format, FormatPart};
+
+/// Checks at compile time that the format is valid, e.g.
+/// `checked_format!("yyyy-mm-dd")`, and expands to the
+/// `tommaso_date_formatter::Format` for it. An invalid format fails to compile
+/// with the same message [`Format::parse`] would return.
+///
+/// The format is parsed when the program compiles and the macro expands to its
+/// parts, so nothing is parsed when the expression runs.
+#[proc_macro]
+pub fn checked_format(input: TokenStream) -> TokenStream {
+    let format = parse_macro_input!(input as LitStr);
+    let parsed = match Format::parse(&format.value()) {
+        Ok(v) => v,
+        Err(e) => return syn::Error::new(format.span(), e).to_compile_error().into(),
+    };
+    let (parts, ranges) = parsed.parsed_parts();
+    let parts = parts.iter().map(|part| match part {
+        FormatPart::Section(section) => {
+            let name = format_ident!("{section:?}");
+            quote! {
+                ::tommaso_date_formatter::FormatPart::Section(
+                    ::tommaso_date_formatter::Section::#name
+                )
+            }
+        }
+        FormatPart::Separator(separator) => {
+            let name = format_ident!("{separator:?}");
+            quote! {
+                ::tommaso_date_formatter::FormatPart::Separator(
+                    ::tommaso_date_formatter::Separator::#name
+                )
+            }
+        }
+        FormatPart::Literal(text) => quote! {
+            ::tommaso_date_formatter::FormatPart::Literal(::core::convert::From::from(#text))
+        },
+    });
+    let ranges = ranges.iter().map(|v| {
+        let (start, end) = (v.start, v.end);
+        quote!(#start..#end)
+    });
+
+    quote! {
+        ::tommaso_date_formatter::Format::from_parsed(
+            #format,
+            [#(#parts),*],
+            [#(#ranges),*],
+        )
+    }
+    .into()
+}
@@ -0,0 +1,6 @@
+#[test]
+fn checked_format() {
+    let cases = trybuild::TestCases::new();
+    cases.pass("tests/ui/good.rs");
+    cases.compile_fail("tests/ui/bad.rs");
+}
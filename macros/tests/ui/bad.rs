@@ -0,0 +1,5 @@
+use tommaso_date_formatter_macros::checked_format;
+
+fn main() {
+    let _ = checked_format!("yyyy-xx");
+}
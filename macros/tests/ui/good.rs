@@ -0,0 +1,15 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::Format;
+use tommaso_date_formatter_macros::checked_format;
+
+fn main() {
+    let format = checked_format!("yyyy-mm-dd");
+    let date = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    assert_eq!(format.apply(&date), "2024-03-02");
+    let format = checked_format!("do [of] mmmm");
+    assert_eq!(format, Format::parse("do [of] mmmm").unwrap());
+    assert_eq!(format.apply(&date), "2nd of March");
+    let sources: Vec<&str> = format.parts().map(|v| v.source).collect();
+    assert_eq!(sources, ["do", " ", "[of]", " ", "mmmm"]);
+    assert_eq!(checked_format!("iso"), Format::parse("iso").unwrap());
+}
@@ -0,0 +1,13 @@
+use clap::builder::ValueParser;
+
+use crate::Format;
+
+/// Returns a parser for the arguments of a clap command that are formats, so
+/// that an invalid format is rejected along with the other arguments, e.g.
+/// `#[arg(value_parser = tommaso_date_formatter::clap_parser())]`. The value
+/// of the argument is a [`Format`].
+///
+/// Only available with the `clap` feature.
+pub fn clap_parser() -> ValueParser {
+    ValueParser::new(|value: &str| Format::parse(value))
+}
@@ -0,0 +1,71 @@
+use core::{
+    ffi::{c_char, CStr},
+    ptr,
+};
+
+use crate::{format_ymd, FormatError};
+
+/// The date was written to the buffer.
+pub const TDF_OK: i32 = 0;
+/// The pattern or the buffer is a null pointer.
+pub const TDF_NULL_POINTER: i32 = 1;
+/// The pattern is not valid UTF-8.
+pub const TDF_INVALID_UTF8: i32 = 2;
+/// The year, the month and the day don't make a valid date.
+pub const TDF_INVALID_DATE: i32 = 3;
+/// The pattern is not a valid format.
+pub const TDF_INVALID_FORMAT: i32 = 4;
+/// The buffer is too small, so only the start of the date was written.
+pub const TDF_TRUNCATED: i32 = 5;
+
+/// Same as [`format_ymd`], to be called from C, writing the date to the buffer
+/// `out` of `out_len` bytes as a NUL-terminated UTF-8 string. Returns
+/// [`TDF_OK`] or one of the other `TDF_` error codes.
+///
+/// When the buffer is too small as many whole characters as fit are written,
+/// still followed by a NUL, and [`TDF_TRUNCATED`] is returned.
+///
+/// Only available with the `ffi` feature. The `tommaso_date_formatter_ffi`
+/// crate builds it as a shared and a static C library, with the declarations
+/// in its `include/tommaso_date_formatter.h`.
+///
+/// # Safety
+///
+/// `pattern` must be null or point to a NUL-terminated string, and `out` must
+/// be null or point to at least `out_len` writable bytes.
+#[no_mangle]
+pub unsafe extern "C" fn tdf_format(
+    year: i32,
+    month: u32,
+    day: u32,
+    pattern: *const c_char,
+    out: *mut c_char,
+    out_len: usize,
+) -> i32 {
+    if pattern.is_null() || out.is_null() {
+        return TDF_NULL_POINTER;
+    }
+    let Ok(pattern) = CStr::from_ptr(pattern).to_str() else {
+        return TDF_INVALID_UTF8;
+    };
+    let text = match format_ymd(year, month, day, pattern) {
+        Ok(text) => text,
+        Err(FormatError::InvalidDate) => return TDF_INVALID_DATE,
+        Err(_) => return TDF_INVALID_FORMAT,
+    };
+    if out_len == 0 {
+        return TDF_TRUNCATED;
+    }
+    let len = (0..=text.len().min(out_len - 1))
+        .rev()
+        .find(|&v| text.is_char_boundary(v))
+        .expect("0 is a char boundary");
+    ptr::copy_nonoverlapping(text.as_ptr(), out.cast::<u8>(), len);
+    *out.add(len) = 0;
+
+    if len < text.len() {
+        TDF_TRUNCATED
+    } else {
+        TDF_OK
+    }
+}
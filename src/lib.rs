@@ -1,12 +1,71 @@
-use std::error::Error;
+#![cfg_attr(not(feature = "std"), no_std)]
 
-use chrono::{Datelike, NaiveDate};
-use enum_iterator::{all, Sequence};
-use phf::phf_ordered_map;
+extern crate alloc;
+
+use alloc::{
+    borrow::Cow,
+    string::{String, ToString},
+    vec,
+    vec::Vec,
+};
+use core::{
+    error::Error,
+    fmt::{self, Write},
+    mem,
+    ops::Range,
+    str::FromStr,
+};
+
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike, Weekday};
+use enum_iterator::{all, reverse_all, Sequence};
+
+#[cfg(feature = "clap")]
+mod cli;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod lint;
+mod locale;
+mod macros;
+mod parse;
+#[cfg(feature = "std")]
+mod preset;
+mod relative;
+mod span;
+mod strftime;
+#[cfg(feature = "wasm")]
+mod wasm;
+
+#[cfg(feature = "clap")]
+pub use cli::clap_parser;
+#[cfg(feature = "ffi")]
+pub use ffi::{
+    tdf_format, TDF_INVALID_DATE, TDF_INVALID_FORMAT, TDF_INVALID_UTF8, TDF_NULL_POINTER, TDF_OK,
+    TDF_TRUNCATED,
+};
+pub use lint::{lint, Lint};
+pub use locale::{
+    month_name, month_names, weekday_headers, weekday_name, EnglishNames, Locale, NameProvider,
+    NameStyle,
+};
+pub use parse::{parse, parse_with_pivot, roundtrip_ok, try_parse, YearPivot};
+#[cfg(feature = "std")]
+pub use preset::{format_preset, register_preset, Presets};
+pub use relative::format_relative;
+pub use span::{format_age, format_span};
+pub use strftime::format_strftime_like;
+#[cfg(feature = "wasm")]
+pub use wasm::format_wasm;
 
 /**
-Returns a string representing the `date` in the specified `format`.
-A valid format consists of any combination of sections separated by separators.
+Returns a string representing the `date` in the specified `format`. The date
+can be of any type implementing [`Datelike`], e.g. a `NaiveDate` or a
+`DateTime` in a time zone, and only its date is displayed.
+A valid format consists of any combination of sections and separators.
+Sections don't need to be separated, e.g. `yyyymmdd`: when they are adjacent
+the longest matching section is always taken first, so `mmdd` is read as `mm`
+followed by `dd`.
+
+The format `iso` is short for `yyyy-mm-dd`, the ISO 8601 calendar date.
 
 ## List of valid separators
 
@@ -14,210 +73,2280 @@ A valid format consists of any combination of sections separated by separators.
 - `.` Full stop, dot or point (period)
 - `-` Hyphen (dash)
 - ` ` Space
+- `:` Colon
+- `,` Comma
+- `_` Low line (underscore)
+- `@` Commercial at
+
+Separators are displayed exactly as written and never merged, so `dd -- mm`
+gives `02 -- 03` and `dd . / mm` gives `02 . / 03`.
+
+## Literal text
+
+Text between single quotes is displayed as is, e.g. `do 'of' mmmm` gives
+`2nd of March`. Two single quotes stand for a single quote, both inside and
+outside of quoted text, so `'o''clock'` gives `o'clock` and `''` gives `'`.
+
+Text between brackets is displayed as is too, e.g. `[Week] ww` gives `Week 09`.
+The text ends at the first closing bracket, and two opening brackets stand for
+an opening bracket, so `[[[Week] ww` gives `[Week 09`.
+
+Empty brackets display nothing, so they can keep two sections apart, e.g.
+`d[]o` gives `262` on the 2nd of March, while `do` gives `2nd`.
+
+A backslash displays the character after it as is, e.g. `dd\.mm` gives
+`02.03` with a literal period rather than a separator, and `\[yyyy\]` gives
+`[2024]`.
 
 ## List of valid sections
 
 - `yy` Two-digit year, e.g. `24`
-- `yyyy` Four-digit year, e.g. `2024`
+- `yyyy` Four-digit year, e.g. `2024` or `0987`
+- `yyyyy` Signed year with at least five digits as in ISO 8601, e.g. `+02024`
+- `fy` Fiscal year, e.g. `2024`
+- `cc` Century, counting from the 1st century, e.g. `21`
+- `c` Year without its last two digits, e.g. `20`
+- `leap` Whether the year is a leap year, either `true` or `false`
 - `m` One-digit month for months below 10, e.g. `3`
 - `mm` Two-digit month, e.g. `03`
 - `mmm` Three-letter abbreviation for month, e.g. `Mar`
 - `mmmm` Month spelled out in full, e.g. `March`
+- `mmmmm` Narrow name of the month, e.g. `M`
+- `mmr` Month in Roman numerals, e.g. `III`
 - `d` One-digit day of the month for days below 10, e.g. `2`
 - `dd` Two-digit day of the month, e.g. `02`
+- `do` Day of the month with an ordinal suffix, e.g. `2nd`
 - `ddd` Three-letter abbreviation for day of the week, e.g. `Fri`
 - `dddd` Day of the week spelled out in full, e.g. `Friday`
+- `ddddd` Narrow name of the day of the week, e.g. `F`
+- `dim` Number of days in the month, e.g. `31`
+- `e` ISO 8601 day of the week from `1` (Monday) to `7` (Sunday), e.g. `5`
+- `ee` Two-digit ISO 8601 day of the week, e.g. `05`
+- `eus` Day of the week from `1` (Sunday) to `7` (Saturday) as in the US, e.g.
+  `6`
+- `nthdow` Occurrence of the day of the week in the month (1-5), e.g. `1` for
+  the first Saturday
+- `q` Quarter of the year, e.g. `1`
+- `qq` Quarter of the year prefixed with a label, e.g. `Q1`
+- `hy` Half of the year, e.g. `1`
+- `hyy` Half of the year prefixed with `H`, e.g. `H1`
+- `season` Meteorological season, e.g. `Spring`
+- `w` ISO 8601 week of the year, e.g. `9`
+- `ww` Two-digit ISO 8601 week of the year, e.g. `09`
+- `wm` Week of the month, where the week with the 1st of the month is the
+  first, e.g. `1`
+- `isoyy` Two-digit ISO 8601 week-numbering year, e.g. `24`
+- `isoyyyy` Four-digit ISO 8601 week-numbering year, e.g. `2024`
+- `o` Day of the year, e.g. `61`
+- `ooo` Three-digit day of the year, e.g. `061`
+- `doyr` Number of days remaining in the year, e.g. `305`
+- `g` Abbreviated era, e.g. `AD`
+- `gggg` Era spelled out in full, e.g. `Anno Domini`
+- `jd` Julian day number, e.g. `2460372`
+- `epochdays` Days since the 1st of January 1970, negative before it, e.g. `19784`
+
+The week sections and `e`/`ee` count from Monday by default, use a
+[`WeekConfig`] with [`format_with_options`] to start the week on another day.
+
+The Julian day number counts the days since the 1st of January 4713 BC in the
+proleptic Julian calendar (the 24th of November 4714 BC in the proleptic
+Gregorian calendar), so the 1st of January 2000 is day `2451545`. Astronomers
+start the Julian day at noon, but here the whole calendar day gets the number
+of the Julian day that starts at its noon.
+
+Narrow names are usually a single letter, so they are not unique: in English
+both June and July are `J`.
+
+Years follow the astronomical numbering used by chrono, so year `0` is 1 BC,
+year `-1` is 2 BC and so on. The era sections only tell whether the year is
+positive (`AD`) or not (`BC`), they don't change how the year is displayed.
+The week-numbering year is the year the ISO 8601 week belongs to, which
+differs from the calendar year around the 1st of January: the 1st of January
+2021 is in week 53 of 2020, and the 30th of December 2019 is in week 1 of 2020.
+It always follows ISO 8601, so it only goes with `ww` and `e` when the week
+starts on Monday, e.g. `isoyyyy-[W]ww-e`.
+`yyyy` pads the year with zeros to at least four digits, so the year 5 gives
+`0005` and the year 12345 gives `12345`. A negative year keeps its sign in
+front of the padding, e.g. `-0044`, and a positive year can start with a `+`
+too with [`FormatOptions::sign_years`], e.g. `+2024`.
+`yyyyy` always starts with the sign, e.g. `-00044`, and shows longer years in
+full, e.g. `+100000`.
+Every date chrono can represent can be displayed, from the 1st of January of
+the year -262143 ([`NaiveDate::MIN`]) to the 31st of December of the year
+262142 ([`NaiveDate::MAX`]). The numbers never overflow, and the years of `fy`
+and `isoyyyy` are padded like `yyyy`.
+
+A fiscal year is named after the calendar year it ends in. By default it is
+the same as the calendar year, but with a [`FormatOptions::fiscal_start`] of `7`
+the dates from July 2024 to June 2025 are in the fiscal year `2025`, e.g.
+`[FY]fy` gives `FY2025`.
+
+People count centuries in two ways: the 21st century goes from 2001 to 2100,
+which is what `cc` shows, while `c` shows the year without its last two
+digits, so 2000 gives `20` with both, while 2001 gives `21` with `cc` but `20`
+with `c`. The year is `cyy`, while `ccyy` is an error, since it would show
+`2124` in 2024, the 24th year of the 21st century.
+
+Adjacent sections are split so that the whole run is made of sections, taking
+the longest one first, e.g. `mmmr` is `m` followed by `mmr`, since `mmm` would
+leave `r`.
+
+The seasons are the meteorological ones, made of whole months: in the
+northern hemisphere spring goes from March to May, summer from June to
+August, autumn from September to November and winter from December to
+February. Use a [`Hemisphere`] with [`format_with_options`] for the southern
+hemisphere, where March is in autumn.
+
+`yy` always shows the last two digits of the astronomical year, so year `-44`
+gives `56`, consistently with how `yy` is read back.
 
 There is no locale that is specifically followed, but the names for the days and
 months are taken from
-[The Unicode Common Locale Data Repository](https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-dates-modern/main/en/ca-gregorian.json)
+[The Unicode Common Locale Data Repository](https://github.com/unicode-org/cldr-json/blob/main/cldr-json/cldr-dates-modern/main/en/ca-gregorian.json).
+Use [`format_localized`] to display them in another language, which also
+changes the ordinal suffix of `do`, e.g. `1er` and `2e` in French, and the
+label of `qq`, e.g. `T1` in Italian.
+
+## List of valid time sections
+
+- `h` Hour of the day (0-23), e.g. `9`
+- `hh` Two-digit hour of the day (00-23), e.g. `09`
+- `nn` Two-digit minute, e.g. `05`
+- `ss` Two-digit second, e.g. `07`
+- `h12` Hour of the day on a 12-hour clock (1-12), e.g. `9`
+- `hh12` Two-digit hour of the day on a 12-hour clock (01-12), e.g. `09`
+- `tt` Half of the day, either `AM` or `PM`
+- `t` Half of the day abbreviated, either `A` or `P`
+- `aa` Lowercase half of the day, either `am` or `pm`
+- `a` Lowercase half of the day abbreviated, either `a` or `p`
+
+Minutes use `n` because `m` is taken by the month. On a 12-hour clock midnight
+is `12 AM` and noon is `12 PM`. Time sections are meant to be
+used with [`format_datetime`], when formatting a date they display midnight.
 */
-pub fn format(date: &NaiveDate, format: &str) -> Result<String, Box<dyn Error>> {
-    let parts: Vec<FormatPart> = split_format(format)
+pub fn format(date: &impl Datelike, format: &str) -> Result<String, FormatError> {
+    Ok(Format::parse(format)?.apply(date))
+}
+
+/// Same as [`format`](fn@format), but returns `None` instead of the reason why `format`
+/// is not valid, for formats that are known to be valid.
+pub fn format_opt(date: &impl Datelike, format: &str) -> Option<String> {
+    crate::format(date, format).ok()
+}
+
+/// Same as [`format`](fn@format), but the date is made of its `year`, `month` and `day`,
+/// failing with [`FormatError::InvalidDate`] if they don't make a valid date.
+pub fn format_ymd(year: i32, month: u32, day: u32, format: &str) -> Result<String, FormatError> {
+    let date = NaiveDate::from_ymd_opt(year, month, day).ok_or(FormatError::InvalidDate)?;
+    crate::format(&date, format)
+}
+
+/// Returns a string representing the `date` in the ISO 8601 format
+/// `yyyy-mm-dd`, e.g. `2024-03-02`.
+pub fn format_iso(date: &impl Datelike) -> String {
+    apply_parts(&ISO_PARTS, date)
+}
+
+/// Returns a string representing the `date` in the [`DEFAULT_FORMAT`], e.g.
+/// `2024-03-02`.
+pub fn format_default(date: &impl Datelike) -> String {
+    format_iso(date)
+}
+
+/// Returns a string representing the `date` as an ISO 8601 week date
+/// `isoyyyy-[W]ww-e`, e.g. `2024-W09-6`. The week-numbering year can differ
+/// from the calendar year, e.g. the 1st of January 2021 gives `2020-W53-5`.
+pub fn format_iso_week(date: &impl Datelike) -> String {
+    let parts = [
+        FormatPart::Section(Section::ISOYYYY),
+        FormatPart::Separator(Separator::Hyphen),
+        FormatPart::Literal(String::from("W")),
+        FormatPart::Section(Section::WW),
+        FormatPart::Separator(Separator::Hyphen),
+        FormatPart::Section(Section::E),
+    ];
+
+    apply_parts(&parts, date)
+}
+
+/// Same as [`format`](fn@format), but appends the result to `out` instead of allocating a
+/// new string.
+pub fn format_into(
+    date: &impl Datelike,
+    format: &str,
+    out: &mut String,
+) -> Result<(), FormatError> {
+    Format::parse(format)?.apply_into(date, out);
+
+    Ok(())
+}
+
+/// Same as [`format`](fn@format), but for a date with a time, which can be displayed with
+/// the time sections.
+pub fn format_datetime(datetime: &NaiveDateTime, format: &str) -> Result<String, FormatError> {
+    Ok(Format::parse(format)?.apply_datetime(datetime))
+}
+
+/// Returns a string for each of the `dates` in the specified `format`, which is
+/// only parsed once. Each string is written in a buffer with room for the
+/// [`Format::capacity_hint`], which is then taken as is, without copying it.
+pub fn format_many(dates: &[impl Datelike], format: &str) -> Result<Vec<String>, FormatError> {
+    let format = Format::parse(format)?;
+    let capacity = format.capacity_hint();
+    let mut buffer = String::new();
+
+    Ok(dates
         .iter()
-        .map(|&v| FormatPart::try_from(v))
-        .collect::<Result<Vec<_>, _>>()?;
-
-    Ok(parts
-        .into_iter()
-        .map(|v| match v {
-            FormatPart::Section(section) => section.format(date),
-            FormatPart::Separator(separator) => separator.value().to_string(),
+        .map(|v| {
+            buffer.reserve(capacity);
+            format.apply_into(v, &mut buffer);
+            mem::take(&mut buffer)
         })
-        .reduce(|acc, v| format!("{}{}", acc, v))
-        .ok_or("No part found")?)
+        .collect())
 }
 
-fn split_format(format: &str) -> Vec<&str> {
-    let mut raw_sections = Vec::new();
-    let mut last = 0;
-    for (index, separator) in format.match_indices(|c: char| {
-        all::<Separator>()
-            .map(|v| v.value().to_string())
-            .any(|v| v == c.to_string())
-    }) {
-        if last != index {
-            raw_sections.push(&format[last..index]);
+/// Same as [`format`](fn@format), but the names of the months and of the days of the week
+/// are in the language of `locale`.
+pub fn format_localized(
+    date: &impl Datelike,
+    format: &str,
+    locale: Locale,
+) -> Result<String, FormatError> {
+    Ok(Format::parse(format)?.apply_localized(date, locale))
+}
+
+/// Same as [`format`](fn@format), but the names of the months and of the days of the week
+/// are taken from `provider`.
+pub fn format_with_provider(
+    date: &impl Datelike,
+    format: &str,
+    provider: &dyn NameProvider,
+) -> Result<String, FormatError> {
+    Ok(Format::parse(format)?.apply_with_provider(date, provider))
+}
+
+/// Same as [`format`](fn@format), with the specified `options`.
+pub fn format_with_options(
+    date: &impl Datelike,
+    format: &str,
+    options: &FormatOptions,
+) -> Result<String, FormatError> {
+    Ok(Format::parse(format)?.apply_with_options(date, options))
+}
+
+/// Same as [`format`](fn@format), but `custom` is asked first what each section displays,
+/// and when it returns `None` the section is displayed as usual, e.g. to
+/// always display `mmm` in uppercase.
+pub fn format_with(
+    date: &impl Datelike,
+    format: &str,
+    custom: impl Fn(Section, &NaiveDate) -> Option<String>,
+) -> Result<String, FormatError> {
+    Ok(Format::parse(format)?.apply_with(date, custom))
+}
+
+/// Checks that `format` is valid, reporting all of its unsupported parts at
+/// once instead of stopping at the first one like [`format`](fn@format) does.
+pub fn validate(format: &str) -> Result<(), Vec<FormatError>> {
+    if format == ISO_KEYWORD {
+        return validate(ISO_FORMAT);
+    }
+    let mut is_empty = true;
+    let mut errors = Vec::new();
+    split_format(format, &ParseOptions::default(), |raw| {
+        is_empty = false;
+        if let Err(e) = FormatPart::try_from(raw) {
+            errors.push(e);
+        }
+        Ok(())
+    })
+    .map_err(|e| vec![e])?;
+    if is_empty {
+        return Err(vec![FormatError::EmptyFormat]);
+    }
+    if !errors.is_empty() {
+        return Err(errors);
+    }
+
+    Ok(())
+}
+
+/// Encloses `text` in brackets so that it is displayed as is when it's part of
+/// a format, even if it contains sections, e.g. `escape("ddmm")` gives
+/// `[ddmm]`. A `]` and a `[` at the start are written after a backslash
+/// instead, so that the result never runs into the text around it, e.g.
+/// `escape("a")` followed by `escape("b")` is `[a][b]`, which displays `ab`.
+pub fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len() + 2);
+    for (index, chunk) in text.split(']').enumerate() {
+        if index > 0 {
+            escaped.push_str("\\]");
+        }
+        let rest = chunk.trim_start_matches('[');
+        for _ in 0..chunk.len() - rest.len() {
+            escaped.push_str("\\[");
+        }
+        if !rest.is_empty() {
+            escaped.push('[');
+            escaped.push_str(rest);
+            escaped.push(']');
+        }
+    }
+
+    escaped
+}
+
+/// Returns every section along with a short description of what it displays,
+/// e.g. `("yyyy", "Four-digit year")`.
+pub fn supported_tokens() -> &'static [(&'static str, &'static str)] {
+    &SUPPORTED_TOKENS
+}
+
+/// Returns every separator along with a short description, e.g.
+/// `("/", "Oblique stroke (slash)")`.
+pub fn supported_separators() -> &'static [(&'static str, &'static str)] {
+    &SUPPORTED_SEPARATORS
+}
+
+/// Lists the value and the description of each of the `variants` of `kind`,
+/// which must all be there in order.
+macro_rules! catalog {
+    ($kind:ident: $($variant:ident),* $(,)?) => {
+        [$(($kind::$variant.value(), $kind::$variant.description())),*]
+    };
+}
+
+static SUPPORTED_TOKENS: [(&str, &str); <Section as Sequence>::CARDINALITY] = catalog![
+    Section:
+    YY, YYYY, YYYYY, FY, CC, C, LEAP, M, MM, MMM, MMMM, MMMMM, MMR, D, DD, DO, DDD, DDDD,
+    DDDDD, DIM, E, EE, EUS, NTHDOW, Q, QQ, HY, HYY, SEASON, W, WW, WM, ISOYY, ISOYYYY, O, OOO,
+    DOYR, G, GGGG, JD, EPOCHDAYS, H, HH, NN, SS, H12, HH12, TT, T, AA, A
+];
+
+static SUPPORTED_SEPARATORS: [(&str, &str); <Separator as Sequence>::CARDINALITY] = catalog![
+    Separator: Slash, Period, Hyphen, Space, Colon, Comma, Underscore, At
+];
+
+const ISO_KEYWORD: &str = "iso";
+const ISO_FORMAT: &str = "yyyy-mm-dd";
+/// The parts of [`ISO_FORMAT`], so that it doesn't have to be parsed to
+/// display a date in it.
+const ISO_PARTS: [FormatPart; 5] = [
+    FormatPart::Section(Section::YYYY),
+    FormatPart::Separator(Separator::Hyphen),
+    FormatPart::Section(Section::MM),
+    FormatPart::Separator(Separator::Hyphen),
+    FormatPart::Section(Section::DD),
+];
+
+/// The format used by [`format_default`], which is the ISO 8601 calendar date.
+pub const DEFAULT_FORMAT: &str = ISO_FORMAT;
+
+/// A format that has already been parsed, so that it can be applied to any
+/// number of dates without splitting and validating it every time.
+///
+/// See [`format`](fn@format) for what makes a valid format.
+///
+/// With the `serde` feature a format is serialized as the pattern it is
+/// displayed as, e.g. `"yyyy-mm-dd"`, which is read back into the same parts
+/// whatever options it was parsed with, and deserializing fails if the pattern
+/// is not valid.
+#[derive(Debug, Clone)]
+pub struct Format {
+    parts: Vec<FormatPart>,
+    /// The pattern the format was parsed from.
+    pattern: String,
+    /// The byte range of each part in the pattern.
+    ranges: Vec<Range<usize>>,
+    /// The text displayed for every date, when the format doesn't contain any
+    /// section.
+    constant: Option<String>,
+}
+
+impl Format {
+    /// Parses `format`, failing if any of its parts is not supported.
+    pub fn parse(format: &str) -> Result<Self, FormatError> {
+        Self::parse_with(format, &ParseOptions::default())
+    }
+
+    /// Same as [`Format::parse`], with the specified `options`.
+    pub fn parse_with(format: &str, options: &ParseOptions) -> Result<Self, FormatError> {
+        if format == ISO_KEYWORD {
+            let iso = Self::parse_with(ISO_FORMAT, options)?;
+            let ranges = vec![0..format.len(); iso.parts.len()];
+            return Ok(Self::new(iso.parts, format.to_string(), ranges));
+        }
+        let mut parts = Vec::new();
+        let mut ranges = Vec::new();
+        split_format(format, options, |raw| {
+            ranges.push(raw.position..raw.position + raw.value.len());
+            parts.push(FormatPart::try_from(raw)?);
+            Ok(())
+        })?;
+        if parts.is_empty() {
+            return Err(FormatError::EmptyFormat);
+        }
+        if options.strict && !parts.iter().any(|v| matches!(v, FormatPart::Section(_))) {
+            return Err(FormatError::NoSection);
+        }
+        if options.reject_duplicates {
+            check_duplicates(&parts)?;
+        }
+
+        Ok(Self::new(parts, format.to_string(), ranges))
+    }
+
+    /// Builds the format made of `parts`, writing a pattern that is parsed
+    /// back into them, the same one the format is displayed as.
+    fn from_parts(parts: Vec<FormatPart>) -> Self {
+        let placeholders = vec![0..0; parts.len()];
+        let (parts, _) = merge_literals(parts, placeholders);
+        let (pattern, ranges) = write_pattern(&parts);
+
+        Self::new(parts, pattern, ranges)
+    }
+
+    fn new(parts: Vec<FormatPart>, pattern: String, ranges: Vec<Range<usize>>) -> Self {
+        let (parts, ranges) = merge_literals(parts, ranges);
+        let constant = parts
+            .iter()
+            .all(|v| !matches!(v, FormatPart::Section(_)))
+            .then(|| parts.iter().map(FormatPart::value).collect());
+
+        Self {
+            parts,
+            pattern,
+            ranges,
+            constant,
+        }
+    }
+
+    /// Builds the format for `pattern` out of the `parts` it was parsed into,
+    /// each at the byte range of the same index in `ranges`, without parsing
+    /// it again. It's what `checked_format!` expands to.
+    #[doc(hidden)]
+    pub fn from_parsed<const N: usize>(
+        pattern: &str,
+        parts: [FormatPart; N],
+        ranges: [Range<usize>; N],
+    ) -> Self {
+        Self::new(Vec::from(parts), pattern.to_string(), Vec::from(ranges))
+    }
+
+    /// Returns the parts of the format along with their byte ranges in the
+    /// pattern, for `checked_format!` to pass them to [`Format::from_parsed`].
+    #[doc(hidden)]
+    pub fn parsed_parts(&self) -> (&[FormatPart], &[Range<usize>]) {
+        (&self.parts, &self.ranges)
+    }
+
+    /// Returns every part of the format along with where it is in the pattern,
+    /// e.g. to highlight the pattern. A format made with a [`FormatBuilder`]
+    /// refers to a pattern equivalent to its parts, while all the parts of the
+    /// `iso` keyword refer to the whole keyword.
+    pub fn parts(&self) -> impl Iterator<Item = PartInfo<'_>> + '_ {
+        self.parts
+            .iter()
+            .zip(&self.ranges)
+            .map(|(part, range)| PartInfo {
+                kind: match part {
+                    FormatPart::Section(_) => PartKind::Section,
+                    FormatPart::Separator(_) => PartKind::Separator,
+                    FormatPart::Literal(_) => PartKind::Literal,
+                },
+                source: &self.pattern[range.clone()],
+                range: range.clone(),
+            })
+    }
+
+    /// Returns whether the format contains `section`, e.g. to require that a
+    /// format displays the day.
+    pub fn contains_section(&self, section: Section) -> bool {
+        self.parts.contains(&FormatPart::Section(section))
+    }
+
+    /// Returns the sections of the format in the order they first appear,
+    /// without repeating them, e.g. `mm` and `yyyy` for `mm/yyyy mm`.
+    pub fn sections(&self) -> Vec<Section> {
+        let mut sections = Vec::new();
+        for part in &self.parts {
+            if let FormatPart::Section(section) = part {
+                if !sections.contains(section) {
+                    sections.push(*section);
+                }
+            }
+        }
+
+        sections
+    }
+
+    /// Returns a string representing the `date` in this format.
+    pub fn apply(&self, date: &impl Datelike) -> String {
+        let mut out = String::with_capacity(self.capacity_hint());
+        self.apply_into(date, &mut out);
+
+        out
+    }
+
+    /// Same as [`Format::apply`], but appends the result to `out` instead of
+    /// allocating a new string.
+    pub fn apply_into(&self, date: &impl Datelike, out: &mut String) {
+        self.write(
+            &at_midnight(date),
+            &EnglishNames,
+            &FormatOptions::default(),
+            out,
+        )
+        .expect("writing to a string doesn't fail");
+    }
+
+    /// Returns a string representing the `datetime` in this format.
+    pub fn apply_datetime(&self, datetime: &NaiveDateTime) -> String {
+        let mut out = String::with_capacity(self.capacity_hint());
+        self.write(datetime, &EnglishNames, &FormatOptions::default(), &mut out)
+            .expect("writing to a string doesn't fail");
+
+        out
+    }
+
+    /// Same as [`Format::apply`], but the names of the months and of the days
+    /// of the week are in the language of `locale`.
+    pub fn apply_localized(&self, date: &impl Datelike, locale: Locale) -> String {
+        self.apply_with_provider(date, &locale)
+    }
+
+    /// Same as [`Format::apply`], but the names of the months and of the days
+    /// of the week are taken from `provider`.
+    pub fn apply_with_provider(&self, date: &impl Datelike, provider: &dyn NameProvider) -> String {
+        let mut out = String::with_capacity(self.capacity_hint());
+        self.write(
+            &at_midnight(date),
+            provider,
+            &FormatOptions::default(),
+            &mut out,
+        )
+        .expect("writing to a string doesn't fail");
+
+        out
+    }
+
+    /// Same as [`Format::apply`], with the specified `options`.
+    pub fn apply_with_options(&self, date: &impl Datelike, options: &FormatOptions) -> String {
+        let mut out = String::with_capacity(self.capacity_hint_with_options(options));
+        self.write(&at_midnight(date), &EnglishNames, options, &mut out)
+            .expect("writing to a string doesn't fail");
+
+        out
+    }
+
+    /// Same as [`format_with`], using this format.
+    pub fn apply_with(
+        &self,
+        date: &impl Datelike,
+        custom: impl Fn(Section, &NaiveDate) -> Option<String>,
+    ) -> String {
+        let datetime = at_midnight(date);
+        let mut out = String::with_capacity(self.capacity_hint());
+        for part in &self.parts {
+            match part {
+                FormatPart::Section(section) => match custom(*section, &datetime.date()) {
+                    Some(text) => out.push_str(&text),
+                    None => section
+                        .write(
+                            &datetime,
+                            &EnglishNames,
+                            &FormatOptions::default(),
+                            &mut out,
+                        )
+                        .expect("writing to a string doesn't fail"),
+                },
+                _ => out.push_str(part.value()),
+            }
+        }
+
+        out
+    }
+
+    /// Same as [`Format::apply`], but when the format doesn't contain any
+    /// section the text, which is the same for every date, is borrowed instead
+    /// of being built again.
+    pub fn render_cow(&self, date: &impl Datelike) -> Cow<'_, str> {
+        match &self.constant {
+            Some(text) => Cow::Borrowed(text),
+            None => Cow::Owned(self.apply(date)),
+        }
+    }
+
+    /// Returns the pieces of text that make up the `date` in this format, one
+    /// for each part, so that they can be streamed without building the whole
+    /// string. Separators, literals and names are borrowed, only numbers are
+    /// allocated.
+    pub fn render<'a>(&'a self, date: &impl Datelike) -> impl Iterator<Item = Cow<'a, str>> + 'a {
+        let datetime = at_midnight(date);
+        self.parts.iter().map(move |part| match part {
+            FormatPart::Section(section) => {
+                match section
+                    .static_text(&datetime)
+                    .or_else(|| section.english_name(&datetime))
+                {
+                    Some(text) => Cow::Borrowed(text),
+                    None => {
+                        let mut out = String::new();
+                        section
+                            .write(
+                                &datetime,
+                                &EnglishNames,
+                                &FormatOptions::default(),
+                                &mut out,
+                            )
+                            .expect("writing to a string doesn't fail");
+                        Cow::Owned(out)
+                    }
+                }
+            }
+            FormatPart::Separator(separator) => Cow::Borrowed(separator.value()),
+            FormatPart::Literal(text) => Cow::Borrowed(text.as_str()),
+        })
+    }
+
+    /// Returns roughly the longest length in bytes of a date displayed in this
+    /// format, so that a buffer can be allocated beforehand. Names are
+    /// measured in English, so in other languages the length may be exceeded.
+    pub fn capacity_hint(&self) -> usize {
+        self.capacity_hint_with_options(&FormatOptions::default())
+    }
+
+    /// Same as [`Format::capacity_hint`], for a date displayed with the
+    /// specified `options`, e.g. with [`FormatOptions::sign_years`] `yyyy` can
+    /// be one byte longer.
+    pub fn capacity_hint_with_options(&self, options: &FormatOptions) -> usize {
+        self.parts
+            .iter()
+            .map(|v| match v {
+                FormatPart::Section(section) => section.max_len(options),
+                _ => v.value().len(),
+            })
+            .sum()
+    }
+
+    fn write<W: Write>(
+        &self,
+        datetime: &NaiveDateTime,
+        names: &dyn NameProvider,
+        options: &FormatOptions,
+        out: &mut W,
+    ) -> fmt::Result {
+        write_parts(&self.parts, datetime, names, options, out)
+    }
+}
+
+/// Displays `datetime` in the format made of `parts`.
+fn write_parts<W: Write>(
+    parts: &[FormatPart],
+    datetime: &NaiveDateTime,
+    names: &dyn NameProvider,
+    options: &FormatOptions,
+    out: &mut W,
+) -> fmt::Result {
+    for part in parts {
+        match part {
+            FormatPart::Section(section) if options.digits == DigitStyle::Ascii => {
+                section.write(datetime, names, options, out)?
+            }
+            FormatPart::Section(section) => section.write(
+                datetime,
+                names,
+                options,
+                &mut DigitWriter {
+                    out: &mut *out,
+                    style: options.digits,
+                },
+            )?,
+            FormatPart::Separator(separator) => out.write_str(separator.value())?,
+            FormatPart::Literal(text) => out.write_str(text)?,
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns a string representing the `date` in the format made of `parts`,
+/// for the formats that are known beforehand and so aren't parsed.
+fn apply_parts(parts: &[FormatPart], date: &impl Datelike) -> String {
+    let mut out = String::new();
+    write_parts(
+        parts,
+        &at_midnight(date),
+        &EnglishNames,
+        &FormatOptions::default(),
+        &mut out,
+    )
+    .expect("writing to a string doesn't fail");
+
+    out
+}
+
+/// Two formats are equal when they have the same parts, even if their patterns
+/// are written differently, e.g. `'at'` and `[at]`.
+impl PartialEq for Format {
+    fn eq(&self, other: &Self) -> bool {
+        self.parts == other.parts
+    }
+}
+
+impl Eq for Format {}
+
+/// Writes a pattern that is parsed back into the same format, in which every
+/// literal is written with [`escape`], e.g. `'at' hh` gives `[at] hh`. Two
+/// sections that would be read as another one are kept apart with `[]`, e.g.
+/// `d` followed by `o` gives `d[]o` rather than `do`.
+impl fmt::Display for Format {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&write_pattern(&self.parts).0)
+    }
+}
+
+impl FromStr for Format {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Format {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Format {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let pattern = <String as serde::Deserialize>::deserialize(deserializer)?;
+        Self::parse(&pattern).map_err(serde::de::Error::custom)
+    }
+}
+
+/// Builds a [`Format`] part by part, so that it is valid by construction.
+///
+/// Each method appends a part, e.g. `FormatBuilder::new().year4().sep_slash()
+/// .month2().build()` is the same as parsing `yyyy/mm`.
+#[derive(Debug, Clone, Default)]
+pub struct FormatBuilder {
+    parts: Vec<FormatPart>,
+}
+
+impl FormatBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the format built so far. A builder without any part gives a
+    /// format that displays an empty string.
+    pub fn build(self) -> Format {
+        Format::from_parts(self.parts)
+    }
+
+    pub fn section(mut self, section: Section) -> Self {
+        self.parts.push(FormatPart::Section(section));
+        self
+    }
+
+    pub fn separator(mut self, separator: Separator) -> Self {
+        self.parts.push(FormatPart::Separator(separator));
+        self
+    }
+
+    /// Appends text that is displayed as is.
+    pub fn literal(mut self, text: &str) -> Self {
+        self.parts.push(FormatPart::Literal(text.to_string()));
+        self
+    }
+
+    /// Same as `yy`.
+    pub fn year2(self) -> Self {
+        self.section(Section::YY)
+    }
+
+    /// Same as `yyyy`.
+    pub fn year4(self) -> Self {
+        self.section(Section::YYYY)
+    }
+
+    /// Same as `m`.
+    pub fn month1(self) -> Self {
+        self.section(Section::M)
+    }
+
+    /// Same as `mm`.
+    pub fn month2(self) -> Self {
+        self.section(Section::MM)
+    }
+
+    /// Same as `mmm`.
+    pub fn month_abbrev(self) -> Self {
+        self.section(Section::MMM)
+    }
+
+    /// Same as `mmmm`.
+    pub fn month_wide(self) -> Self {
+        self.section(Section::MMMM)
+    }
+
+    /// Same as `d`.
+    pub fn day1(self) -> Self {
+        self.section(Section::D)
+    }
+
+    /// Same as `dd`.
+    pub fn day2(self) -> Self {
+        self.section(Section::DD)
+    }
+
+    /// Same as `ddd`.
+    pub fn weekday_abbrev(self) -> Self {
+        self.section(Section::DDD)
+    }
+
+    /// Same as `dddd`.
+    pub fn weekday_wide(self) -> Self {
+        self.section(Section::DDDD)
+    }
+
+    /// Same as `/`.
+    pub fn sep_slash(self) -> Self {
+        self.separator(Separator::Slash)
+    }
+
+    /// Same as `.`.
+    pub fn sep_period(self) -> Self {
+        self.separator(Separator::Period)
+    }
+
+    /// Same as `-`.
+    pub fn sep_hyphen(self) -> Self {
+        self.separator(Separator::Hyphen)
+    }
+
+    /// Same as ` `.
+    pub fn sep_space(self) -> Self {
+        self.separator(Separator::Space)
+    }
+
+    /// Same as `:`.
+    pub fn sep_colon(self) -> Self {
+        self.separator(Separator::Colon)
+    }
+
+    /// Same as `,`.
+    pub fn sep_comma(self) -> Self {
+        self.separator(Separator::Comma)
+    }
+
+    /// Same as `_`.
+    pub fn sep_underscore(self) -> Self {
+        self.separator(Separator::Underscore)
+    }
+
+    /// Same as `@`.
+    pub fn sep_at(self) -> Self {
+        self.separator(Separator::At)
+    }
+}
+
+/// Options that change how a format is parsed into a [`Format`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ParseOptions {
+    /// Displays as is any text that is neither a section nor a separator,
+    /// instead of failing, e.g. `d° mmmm` gives `2° March`. Quotes and brackets
+    /// still start a literal.
+    pub allow_literals: bool,
+    /// Rejects a format that doesn't contain any section, e.g. `/-/`, since it
+    /// displays the same text for every date, with [`FormatError::NoSection`].
+    pub strict: bool,
+    /// Rejects a format that displays the year, the month or the day more than
+    /// once, e.g. `mm/mmm`, with [`FormatError::DuplicateField`].
+    pub reject_duplicates: bool,
+    /// Matches the sections regardless of case, e.g. `YYYY-MM-DD` is the same
+    /// as `yyyy-mm-dd`. The names are still displayed in their usual case.
+    pub case_insensitive: bool,
+}
+
+/// Options that change how a [`Format`] displays a date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FormatOptions {
+    /// The case of the names of the months and of the days of the week.
+    pub case: OutputCase,
+    /// How `yy`, `mm` and `dd` are padded to two digits.
+    pub pad: PadStyle,
+    /// The day the week starts on, for `w`, `ww`, `wm`, `e` and `ee`.
+    pub week: WeekConfig,
+    /// The month the fiscal year starts in for `fy`, from `1` (January), the
+    /// default, to `12`.
+    pub fiscal_start: u8,
+    /// The digits numbers are displayed with.
+    pub digits: DigitStyle,
+    /// The hemisphere the seasons of `season` are in.
+    pub hemisphere: Hemisphere,
+    /// Ends the abbreviated name of the month of `mmm` with a period, e.g.
+    /// `Mar.`, unless it already ends with one or it's the same as the full
+    /// name, e.g. `May`.
+    pub month_period: bool,
+    /// Starts the year of `yyyy` with a `+` when it isn't negative, e.g.
+    /// `+2024`, like the signed years of ISO 8601. Negative years always
+    /// start with a `-`, e.g. `-0044`.
+    pub sign_years: bool,
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        Self {
+            case: OutputCase::default(),
+            pad: PadStyle::default(),
+            week: WeekConfig::default(),
+            fiscal_start: 1,
+            digits: DigitStyle::default(),
+            hemisphere: Hemisphere::default(),
+            month_period: false,
+            sign_years: false,
+        }
+    }
+}
+
+/// How the weeks are counted.
+///
+/// When the week starts on Monday, the default, the weeks follow ISO 8601, so
+/// the first week of the year is the one containing the first Thursday. When it
+/// starts on any other day the first week of the year is the one containing
+/// the 1st of January, e.g. in the US the weeks start on Sunday.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekConfig {
+    pub first_day: Weekday,
+}
+
+impl Default for WeekConfig {
+    fn default() -> Self {
+        Self {
+            first_day: Weekday::Mon,
+        }
+    }
+}
+
+impl WeekConfig {
+    /// Returns the week of the year of `date`, starting from `1`.
+    fn week(&self, date: &impl Datelike) -> u32 {
+        if self.first_day == Weekday::Mon {
+            return date.iso_week().week();
         }
-        raw_sections.push(separator);
-        last = index + separator.len();
+        let first_of_year = NaiveDate::from_yo_opt(date.year(), 1).expect("the year is valid");
+        (date.ordinal0() + days_since(first_of_year.weekday(), self.first_day)) / 7 + 1
     }
-    if last < format.len() {
-        raw_sections.push(&format[last..]);
+
+    /// Returns the week of the month of `date`, starting from `1` for the
+    /// week containing the 1st of the month, even if it's partial.
+    fn week_of_month(&self, date: &impl Datelike) -> u32 {
+        let first_of_month = date.with_day(1).expect("the 1st of the month is valid");
+        (date.day0() + days_since(first_of_month.weekday(), self.first_day)) / 7 + 1
     }
 
-    raw_sections
+    /// Returns the day of the week of `date`, from `1` for the first day.
+    fn weekday(&self, date: &impl Datelike) -> u32 {
+        days_since(date.weekday(), self.first_day) + 1
+    }
+}
+
+/// Returns how many days `day` comes after `start` in a week.
+fn days_since(day: Weekday, start: Weekday) -> u32 {
+    (7 + day.num_days_from_monday() - start.num_days_from_monday()) % 7
+}
+
+/// The case used to display a name, e.g. `March`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputCase {
+    /// As given by the names, e.g. `March`.
+    #[default]
+    AsIs,
+    /// All uppercase, e.g. `MARCH`.
+    Upper,
+    /// All lowercase, e.g. `march`.
+    Lower,
+    /// Uppercase first letter and lowercase rest, e.g. `March`.
+    Title,
+}
+
+impl OutputCase {
+    fn write<W: Write>(&self, name: &str, out: &mut W) -> fmt::Result {
+        match self {
+            Self::AsIs => out.write_str(name),
+            Self::Upper => out.write_str(&name.to_uppercase()),
+            Self::Lower => out.write_str(&name.to_lowercase()),
+            Self::Title => {
+                let mut chars = name.chars();
+                if let Some(first) = chars.next() {
+                    write!(out, "{}", first.to_uppercase())?;
+                }
+                out.write_str(&chars.as_str().to_lowercase())
+            }
+        }
+    }
+}
+
+/// How a number is padded to its width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PadStyle {
+    /// With zeros, e.g. `02`.
+    #[default]
+    Zero,
+    /// With spaces, e.g. ` 2`.
+    Space,
+    /// Not padded, e.g. `2`.
+    None,
+}
+
+impl PadStyle {
+    fn write<W: Write>(&self, value: impl fmt::Display, width: usize, out: &mut W) -> fmt::Result {
+        match self {
+            Self::Zero => write!(out, "{:0>1$}", value, width),
+            Self::Space => write!(out, "{:>1$}", value, width),
+            Self::None => write!(out, "{}", value),
+        }
+    }
+}
+
+/// The digits numbers are displayed with. Only the digits displayed by the
+/// sections change, not the ones in separators and literals.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DigitStyle {
+    /// e.g. `2024`
+    #[default]
+    Ascii,
+    /// e.g. `٢٠٢٤`
+    ArabicIndic,
+    /// e.g. `२०२४`
+    Devanagari,
+}
+
+impl DigitStyle {
+    /// Returns the digit of this style for `c` if it's an ASCII digit, or `c`.
+    fn digit(&self, c: char) -> char {
+        let zero = match self {
+            Self::Ascii => return c,
+            Self::ArabicIndic => '\u{0660}',
+            Self::Devanagari => '\u{0966}',
+        };
+        c.to_digit(10)
+            .and_then(|v| char::from_u32(zero as u32 + v))
+            .unwrap_or(c)
+    }
 }
 
-#[derive(Debug, Sequence)]
-enum FormatPart {
+/// The hemisphere the seasons are in, which swaps spring with autumn and
+/// summer with winter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Hemisphere {
+    #[default]
+    Northern,
+    Southern,
+}
+
+impl Hemisphere {
+    /// Returns the meteorological season `date` is in.
+    fn season(&self, date: &impl Datelike) -> &'static str {
+        const SEASONS: [&str; 4] = ["Winter", "Spring", "Summer", "Autumn"];
+        let season = (date.month() % 12 / 3) as usize;
+        match self {
+            Self::Northern => SEASONS[season],
+            Self::Southern => SEASONS[(season + 2) % 4],
+        }
+    }
+}
+
+/// Writes to `out` replacing the ASCII digits with the ones of `style`.
+struct DigitWriter<'a, W> {
+    out: &'a mut W,
+    style: DigitStyle,
+}
+
+impl<W: Write> Write for DigitWriter<'_, W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars()
+            .try_for_each(|c| self.out.write_char(self.style.digit(c)))
+    }
+}
+
+/// Displays a date in a [`Format`], without allocating an intermediate string.
+///
+/// Since displaying can't fail, the format has to be parsed beforehand, which
+/// is where an invalid format is reported.
+#[derive(Debug, Clone, Copy)]
+pub struct DateDisplay<'a> {
+    datetime: NaiveDateTime,
+    format: &'a Format,
+}
+
+impl fmt::Display for DateDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.format
+            .write(&self.datetime, &EnglishNames, &FormatOptions::default(), f)
+    }
+}
+
+/// Adds [`DisplayWith::display_with`] to dates, so that they can be used with
+/// `format!`, `write!` and the like in a [`Format`].
+pub trait DisplayWith {
+    /// Returns a value that displays this date in the specified `format`.
+    fn display_with<'a>(&'a self, format: &'a Format) -> DateDisplay<'a>;
+}
+
+impl DisplayWith for NaiveDate {
+    fn display_with<'a>(&'a self, format: &'a Format) -> DateDisplay<'a> {
+        DateDisplay {
+            datetime: self.and_time(NaiveTime::MIN),
+            format,
+        }
+    }
+}
+
+impl DisplayWith for NaiveDateTime {
+    fn display_with<'a>(&'a self, format: &'a Format) -> DateDisplay<'a> {
+        DateDisplay {
+            datetime: *self,
+            format,
+        }
+    }
+}
+
+/// The reasons why formatting or parsing a date can fail.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatError {
+    /// The format contains a part that is neither a section nor a separator,
+    /// starting at the byte offset `position`. When the part is only a typo
+    /// away from a valid section, that section is given as a `suggestion`.
+    UnknownToken {
+        token: String,
+        position: usize,
+        suggestion: Option<&'static str>,
+    },
+    /// The format doesn't contain any part.
+    EmptyFormat,
+    /// The format doesn't contain any section, which is only an error with
+    /// [`ParseOptions::strict`].
+    NoSection,
+    /// The format displays the same field more than once, e.g. the month in
+    /// `mm/mmm`, which is only an error with [`ParseOptions::reject_duplicates`].
+    DuplicateField(&'static str),
+    /// The century at the byte offset `position` is followed by the year of
+    /// the century, as in `ccyy`, which shows `2124` in 2024. The year is
+    /// `cyy`, and `cc[]yy` shows the century followed by the year of the
+    /// century.
+    AmbiguousCentury { position: usize },
+    /// The literal starting at the byte offset `position` is never closed.
+    UnterminatedLiteral { position: usize },
+    /// The input being parsed doesn't match the format at `position`.
+    InvalidInput { position: usize, reason: String },
+    /// The format doesn't contain enough information to build a date, e.g.
+    /// there is no year.
+    MissingField(&'static str),
+    /// The values read don't make a valid date, e.g. the 30th of February.
+    InvalidDate,
+    /// The values read don't make a valid time, e.g. 25 hours.
+    InvalidTime,
+    /// A value read doesn't agree with the date built from the others, e.g.
+    /// the day of the week is wrong.
+    Mismatch(&'static str),
+    /// There is no preset with this name.
+    UnknownPreset(String),
+}
+
+impl fmt::Display for FormatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownToken {
+                token,
+                position,
+                suggestion,
+            } => {
+                write!(
+                    f,
+                    "Part not supported at position {}: \"{}\"",
+                    position, token
+                )?;
+                if let Some(suggestion) = suggestion {
+                    write!(f, ", did you mean \"{}\"?", suggestion)?;
+                }
+                Ok(())
+            }
+            Self::EmptyFormat => write!(f, "No part found"),
+            Self::NoSection => write!(f, "No section found"),
+            Self::DuplicateField(field) => write!(f, "The {} is displayed more than once", field),
+            Self::AmbiguousCentury { position } => write!(
+                f,
+                "Century followed by the year of the century at position {}, did you mean \"cyy\"?",
+                position
+            ),
+            Self::UnterminatedLiteral { position } => {
+                write!(f, "Unterminated literal at position {}", position)
+            }
+            Self::InvalidInput { position, reason } => {
+                write!(f, "{} at position {}", reason, position)
+            }
+            Self::MissingField(field) => write!(f, "The format doesn't contain the {}", field),
+            Self::InvalidDate => write!(f, "Invalid date"),
+            Self::InvalidTime => write!(f, "Invalid time"),
+            Self::Mismatch(field) => write!(f, "The {} doesn't match the date", field),
+            Self::UnknownPreset(name) => write!(f, "Preset not found: \"{}\"", name),
+        }
+    }
+}
+
+impl Error for FormatError {}
+
+/// Checks that none of the year, the month and the day is displayed more than
+/// once by `parts`.
+fn check_duplicates(parts: &[FormatPart]) -> Result<(), FormatError> {
+    let mut fields = Vec::new();
+    for part in parts {
+        let FormatPart::Section(section) = part else {
+            continue;
+        };
+        if let Some(field) = section.field() {
+            if fields.contains(&field) {
+                return Err(FormatError::DuplicateField(field));
+            }
+            fields.push(field);
+        }
+    }
+
+    Ok(())
+}
+
+/// Splits `format` into its parts in a single pass, handing each one to `each`
+/// as soon as it's found. Stops at the first error, either an unterminated
+/// literal or one returned by `each`.
+fn split_format<'a>(
+    format: &'a str,
+    options: &ParseOptions,
+    mut each: impl FnMut(RawPart<'a>) -> Result<(), FormatError>,
+) -> Result<(), FormatError> {
+    let mut position = 0;
+    while let Some(c) = format[position..].chars().next() {
+        let end = if starts_literal(c) {
+            let end = literal_end(format, position)?;
+            each(RawPart {
+                position,
+                value: &format[position..end],
+                kind: RawKind::Quoted,
+            })?;
+            end
+        } else if is_separator(c) {
+            let end = position + c.len_utf8();
+            each(RawPart {
+                position,
+                value: &format[position..end],
+                kind: RawKind::Token,
+            })?;
+            end
+        } else {
+            let end = format[position..]
+                .find(|c| starts_literal(c) || is_separator(c))
+                .map_or(format.len(), |v| position + v);
+            split_sections(format, position, end, options, &mut each)?;
+            end
+        };
+        position = end;
+    }
+
+    Ok(())
+}
+
+fn is_separator(c: char) -> bool {
+    matches!(
+        TOKENS.get(c.encode_utf8(&mut [0; 4])),
+        Some(FormatPart::Separator(_))
+    )
+}
+
+fn starts_literal(c: char) -> bool {
+    c == '\'' || c == '[' || c == '\\'
+}
+
+/// Returns the end of the literal starting at `start`, which is either quoted,
+/// enclosed in brackets or a single character after a backslash. Two
+/// consecutive quotes stand for a quote, both inside and outside of a literal,
+/// while two opening brackets stand for an opening bracket.
+fn literal_end(format: &str, start: usize) -> Result<usize, FormatError> {
+    if let Some(rest) = format[start..].strip_prefix('\\') {
+        return rest
+            .chars()
+            .next()
+            .map(|v| start + 1 + v.len_utf8())
+            .ok_or(FormatError::UnterminatedLiteral { position: start });
+    }
+    if format[start..].starts_with("''") || format[start..].starts_with("[[") {
+        return Ok(start + 2);
+    }
+    if format[start..].starts_with('[') {
+        return format[start..]
+            .find(']')
+            .map(|v| start + v + 1)
+            .ok_or(FormatError::UnterminatedLiteral { position: start });
+    }
+    let mut position = start + 1;
+    loop {
+        let quote = format[position..]
+            .find('\'')
+            .map(|v| position + v)
+            .ok_or(FormatError::UnterminatedLiteral { position: start })?;
+        if !format[quote + 1..].starts_with('\'') {
+            return Ok(quote + 1);
+        }
+        position = quote + 2;
+    }
+}
+
+/// Splits the run of adjacent sections between `start` and `end` into valid
+/// sections with [`sections_of`], handing each one to `each`. If the run can't
+/// be split entirely into valid sections it is handed as is, so that the error
+/// reports the whole run, unless literals are allowed, in which case the text
+/// between the longest sections that match is kept verbatim. Fails with
+/// [`FormatError::AmbiguousCentury`] if `cc` is followed by `yy` in the run.
+fn split_sections<'a>(
+    format: &'a str,
+    start: usize,
+    end: usize,
+    options: &ParseOptions,
+    each: &mut impl FnMut(RawPart<'a>) -> Result<(), FormatError>,
+) -> Result<(), FormatError> {
+    if let Some(sections) = sections_of(&format[start..end], options) {
+        let mut position = start;
+        for (i, section) in sections.iter().enumerate() {
+            if *section == Section::CC && sections.get(i + 1) == Some(&Section::YY) {
+                return Err(FormatError::AmbiguousCentury { position });
+            }
+            each(RawPart {
+                position,
+                value: section.value(),
+                kind: RawKind::Section(*section),
+            })?;
+            position += section.value().len();
+        }
+        return Ok(());
+    }
+    if !options.allow_literals {
+        return each(RawPart {
+            position: start,
+            value: &format[start..end],
+            kind: RawKind::Token,
+        });
+    }
+    let mut verbatim_start = None;
+    let mut position = start;
+    while position < end {
+        let rest = &format[position..end];
+        let Some(section) = longest_section(rest, options) else {
+            verbatim_start.get_or_insert(position);
+            position += rest.chars().next().expect("rest is not empty").len_utf8();
+            continue;
+        };
+        if let Some(verbatim_start) = verbatim_start.take() {
+            each(RawPart {
+                position: verbatim_start,
+                value: &format[verbatim_start..position],
+                kind: RawKind::Verbatim,
+            })?;
+        }
+        each(RawPart {
+            position,
+            value: section.value(),
+            kind: RawKind::Section(section),
+        })?;
+        position += section.value().len();
+    }
+    if let Some(verbatim_start) = verbatim_start {
+        each(RawPart {
+            position: verbatim_start,
+            value: &format[verbatim_start..end],
+            kind: RawKind::Verbatim,
+        })?;
+    }
+
+    Ok(())
+}
+
+/// Returns the longest section `text` starts with.
+fn longest_section(text: &str, options: &ParseOptions) -> Option<Section> {
+    sections_at(text, options).next()
+}
+
+/// Returns the sections `text` starts with, from the longest to the shortest,
+/// looking up each prefix in [`TOKENS`].
+fn sections_at<'a>(text: &'a str, options: &'a ParseOptions) -> impl Iterator<Item = Section> + 'a {
+    (1..=LONGEST_TOKEN.min(text.len()))
+        .rev()
+        .filter_map(move |len| {
+            let prefix = text.get(..len)?;
+            let part = if options.case_insensitive {
+                let mut lowercase = [0; LONGEST_TOKEN];
+                lowercase[..len].copy_from_slice(prefix.as_bytes());
+                lowercase[..len].make_ascii_lowercase();
+                TOKENS.get(core::str::from_utf8(&lowercase[..len]).ok()?)
+            } else {
+                TOKENS.get(prefix)
+            };
+            match part {
+                Some(FormatPart::Section(section)) => Some(*section),
+                _ => None,
+            }
+        })
+}
+
+/// Splits the whole `run` into sections, taking the longest section that
+/// matches unless the rest of the run can't be split after it, e.g. `mmmr` is
+/// `m` and `mmr` rather than `mmm` and `r`. Returns `None` if there is no way to
+/// split it.
+fn sections_of(run: &str, options: &ParseOptions) -> Option<Vec<Section>> {
+    let mut sections = Vec::new();
+    let mut dead_ends = vec![false; run.len()];
+    split_from(run, 0, options, &mut sections, &mut dead_ends).then_some(sections)
+}
+
+/// Pushes to `sections` the sections the rest of `run` from `position` is made
+/// of, returning whether it can be split. `dead_ends` keeps the positions that
+/// can't be split, so that each one is only tried once.
+fn split_from(
+    run: &str,
+    position: usize,
+    options: &ParseOptions,
+    sections: &mut Vec<Section>,
+    dead_ends: &mut [bool],
+) -> bool {
+    if position == run.len() {
+        return true;
+    }
+    if dead_ends[position] {
+        return false;
+    }
+    for section in sections_at(&run[position..], options) {
+        sections.push(section);
+        if split_from(
+            run,
+            position + section.value().len(),
+            options,
+            sections,
+            dead_ends,
+        ) {
+            return true;
+        }
+        sections.pop();
+    }
+    dead_ends[position] = true;
+
+    false
+}
+
+/// A part of the format that hasn't been validated yet, along with the byte
+/// offset where it starts in the format.
+#[derive(Debug, Clone, Copy)]
+struct RawPart<'a> {
+    position: usize,
+    value: &'a str,
+    kind: RawKind,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum RawKind {
+    /// Either a section or a separator, which is not known yet.
+    Token,
+    /// A section already found in [`TOKENS`].
+    Section(Section),
+    /// A literal, along with its quotes or brackets.
+    Quoted,
+    /// Text that is neither a section nor a separator, displayed as is.
+    Verbatim,
+}
+
+/// A part of a [`Format`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormatPart {
     Section(Section),
     Separator(Separator),
+    /// Text displayed as is.
+    Literal(String),
 }
 
+/// The kind of a [`FormatPart`], see [`Format::parts`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PartKind {
+    Section,
+    Separator,
+    Literal,
+}
+
+/// A part of a [`Format`] as written in its pattern, see [`Format::parts`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartInfo<'a> {
+    pub kind: PartKind,
+    /// The text of the part in the pattern, including any quotes or brackets.
+    pub source: &'a str,
+    /// The byte range of the part in the pattern.
+    pub range: Range<usize>,
+}
+
+/// A section of a format, which displays a field of the date. Each variant is
+/// named after the text that stands for it in a format, see [`format`](fn@format) for the
+/// full list.
+///
+/// Every section can be listed with `enum_iterator`, e.g. to suggest them:
+///
+/// ```
+/// use enum_iterator::all;
+/// use tommaso_date_formatter::Section;
+///
+/// let values: Vec<&str> = all::<Section>().map(|v| v.value()).collect();
+/// assert_eq!(&values[..3], ["yy", "yyyy", "yyyyy"]);
+/// ```
 #[allow(clippy::upper_case_acronyms)]
-#[derive(Debug, Sequence)]
-enum Section {
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum Section {
     YY,
     YYYY,
+    YYYYY,
+    FY,
+    CC,
+    C,
+    LEAP,
     M,
     MM,
     MMM,
     MMMM,
+    MMMMM,
+    MMR,
     D,
     DD,
+    DO,
     DDD,
     DDDD,
+    DDDDD,
+    DIM,
+    E,
+    EE,
+    EUS,
+    NTHDOW,
+    Q,
+    QQ,
+    HY,
+    HYY,
+    SEASON,
+    W,
+    WW,
+    WM,
+    ISOYY,
+    ISOYYYY,
+    O,
+    OOO,
+    DOYR,
+    G,
+    GGGG,
+    JD,
+    EPOCHDAYS,
+    H,
+    HH,
+    NN,
+    SS,
+    H12,
+    HH12,
+    TT,
+    T,
+    AA,
+    A,
 }
 
-#[derive(Debug, Sequence)]
-enum Separator {
+/// A separator of a format, which is displayed as is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Sequence)]
+pub enum Separator {
     Slash,
     Period,
     Hyphen,
     Space,
-}
-
-impl TryFrom<&str> for FormatPart {
-    type Error = Box<dyn Error>;
-
-    fn try_from(value: &str) -> Result<Self, Self::Error> {
-        all::<FormatPart>()
-            .find(|v| v.value() == value)
-            .ok_or(format!("Part not supported: {}", value).into())
-    }
+    Colon,
+    Comma,
+    Underscore,
+    At,
 }
 
 impl FormatPart {
-    fn value(&self) -> &str {
+    /// Returns the text that stands for this part in a format. The text of a
+    /// literal is returned without quotes.
+    pub fn value(&self) -> &str {
         match self {
             FormatPart::Section(section) => section.value(),
             FormatPart::Separator(separator) => separator.value(),
+            FormatPart::Literal(text) => text,
+        }
+    }
+}
+
+/// Joins adjacent literals into one and drops the empty ones, since they
+/// display the same text, along with their byte ranges.
+fn merge_literals(
+    parts: Vec<FormatPart>,
+    ranges: Vec<Range<usize>>,
+) -> (Vec<FormatPart>, Vec<Range<usize>>) {
+    let mut merged: Vec<FormatPart> = Vec::with_capacity(parts.len());
+    let mut merged_ranges: Vec<Range<usize>> = Vec::with_capacity(ranges.len());
+    for (part, range) in parts.into_iter().zip(ranges) {
+        match (&part, merged.last_mut()) {
+            (FormatPart::Literal(text), _) if text.is_empty() => {}
+            (FormatPart::Literal(text), Some(FormatPart::Literal(last))) => {
+                last.push_str(text);
+                if let Some(last) = merged_ranges.last_mut() {
+                    last.end = range.end;
+                }
+            }
+            _ => {
+                merged.push(part);
+                merged_ranges.push(range);
+            }
+        }
+    }
+
+    (merged, merged_ranges)
+}
+
+/// Returns a pattern that is parsed back into `parts`, along with the byte
+/// range of each part in it. Literals are escaped, and a section that would be
+/// read together with the sections before it as something else is preceded by
+/// `[]`.
+fn write_pattern(parts: &[FormatPart]) -> (String, Vec<Range<usize>>) {
+    let mut pattern = String::new();
+    let mut ranges = Vec::with_capacity(parts.len());
+    let mut run = Vec::new();
+    for part in parts {
+        match part {
+            FormatPart::Section(section) => {
+                run.push(*section);
+                if !reads_back(&run) {
+                    pattern.push_str("[]");
+                    run = vec![*section];
+                }
+            }
+            _ => run.clear(),
+        }
+        let start = pattern.len();
+        match part {
+            FormatPart::Literal(text) => pattern.push_str(&escape(text)),
+            _ => pattern.push_str(part.value()),
+        }
+        ranges.push(start..pattern.len());
+    }
+
+    (pattern, ranges)
+}
+
+/// Returns whether the `sections` written one after the other are read back
+/// as the same sections.
+fn reads_back(sections: &[Section]) -> bool {
+    let run: String = sections.iter().map(|v| v.value()).collect();
+    let mut read = Vec::with_capacity(sections.len());
+    let split = split_sections(&run, 0, run.len(), &ParseOptions::default(), &mut |raw| {
+        read.push(raw.value);
+        Ok(())
+    });
+
+    split.is_ok() && read.into_iter().eq(sections.iter().map(|v| v.value()))
+}
+
+/// The length of the longest token in [`TOKENS`], `epochdays`.
+const LONGEST_TOKEN: usize = 9;
+
+/// The section or separator each token stands for.
+static TOKENS: phf::Map<&'static str, FormatPart> = phf::phf_map! {
+    "yy" => FormatPart::Section(Section::YY),
+    "yyyy" => FormatPart::Section(Section::YYYY),
+    "yyyyy" => FormatPart::Section(Section::YYYYY),
+    "fy" => FormatPart::Section(Section::FY),
+    "cc" => FormatPart::Section(Section::CC),
+    "c" => FormatPart::Section(Section::C),
+    "leap" => FormatPart::Section(Section::LEAP),
+    "m" => FormatPart::Section(Section::M),
+    "mm" => FormatPart::Section(Section::MM),
+    "mmm" => FormatPart::Section(Section::MMM),
+    "mmmm" => FormatPart::Section(Section::MMMM),
+    "mmmmm" => FormatPart::Section(Section::MMMMM),
+    "mmr" => FormatPart::Section(Section::MMR),
+    "d" => FormatPart::Section(Section::D),
+    "dd" => FormatPart::Section(Section::DD),
+    "do" => FormatPart::Section(Section::DO),
+    "ddd" => FormatPart::Section(Section::DDD),
+    "dddd" => FormatPart::Section(Section::DDDD),
+    "ddddd" => FormatPart::Section(Section::DDDDD),
+    "dim" => FormatPart::Section(Section::DIM),
+    "e" => FormatPart::Section(Section::E),
+    "ee" => FormatPart::Section(Section::EE),
+    "eus" => FormatPart::Section(Section::EUS),
+    "nthdow" => FormatPart::Section(Section::NTHDOW),
+    "q" => FormatPart::Section(Section::Q),
+    "qq" => FormatPart::Section(Section::QQ),
+    "hy" => FormatPart::Section(Section::HY),
+    "hyy" => FormatPart::Section(Section::HYY),
+    "season" => FormatPart::Section(Section::SEASON),
+    "w" => FormatPart::Section(Section::W),
+    "ww" => FormatPart::Section(Section::WW),
+    "wm" => FormatPart::Section(Section::WM),
+    "isoyy" => FormatPart::Section(Section::ISOYY),
+    "isoyyyy" => FormatPart::Section(Section::ISOYYYY),
+    "o" => FormatPart::Section(Section::O),
+    "ooo" => FormatPart::Section(Section::OOO),
+    "doyr" => FormatPart::Section(Section::DOYR),
+    "g" => FormatPart::Section(Section::G),
+    "gggg" => FormatPart::Section(Section::GGGG),
+    "jd" => FormatPart::Section(Section::JD),
+    "epochdays" => FormatPart::Section(Section::EPOCHDAYS),
+    "h" => FormatPart::Section(Section::H),
+    "hh" => FormatPart::Section(Section::HH),
+    "nn" => FormatPart::Section(Section::NN),
+    "ss" => FormatPart::Section(Section::SS),
+    "h12" => FormatPart::Section(Section::H12),
+    "hh12" => FormatPart::Section(Section::HH12),
+    "tt" => FormatPart::Section(Section::TT),
+    "t" => FormatPart::Section(Section::T),
+    "aa" => FormatPart::Section(Section::AA),
+    "a" => FormatPart::Section(Section::A),
+    "/" => FormatPart::Separator(Separator::Slash),
+    "." => FormatPart::Separator(Separator::Period),
+    "-" => FormatPart::Separator(Separator::Hyphen),
+    " " => FormatPart::Separator(Separator::Space),
+    ":" => FormatPart::Separator(Separator::Colon),
+    "," => FormatPart::Separator(Separator::Comma),
+    "_" => FormatPart::Separator(Separator::Underscore),
+    "@" => FormatPart::Separator(Separator::At),
+};
+
+impl TryFrom<RawPart<'_>> for FormatPart {
+    type Error = FormatError;
+
+    fn try_from(raw: RawPart) -> Result<Self, Self::Error> {
+        match raw.kind {
+            RawKind::Token => {}
+            RawKind::Section(section) => return Ok(FormatPart::Section(section)),
+            RawKind::Quoted => return Ok(FormatPart::Literal(unquote(raw.value))),
+            RawKind::Verbatim => return Ok(FormatPart::Literal(raw.value.to_string())),
+        }
+        TOKENS
+            .get(raw.value)
+            .cloned()
+            .ok_or_else(|| FormatError::UnknownToken {
+                token: raw.value.to_string(),
+                position: raw.position,
+                suggestion: suggest_section(raw.value),
+            })
+    }
+}
+
+/// Returns the text of a literal, e.g. `o'clock` for `'o''clock'`, `Week` for
+/// `[Week]` or `.` for `\.`.
+fn unquote(value: &str) -> String {
+    match value {
+        "''" => "'".to_string(),
+        "[[" => "[".to_string(),
+        _ if value.starts_with('\\') => value[1..].to_string(),
+        _ if value.starts_with('[') => value[1..value.len() - 1].to_string(),
+        _ => value[1..value.len() - 1].replace("''", "'"),
+    }
+}
+
+/// Returns the section that is only one edit away from `value`, preferring the
+/// longest one when there is more than one, and the one that comes first among
+/// the sections of the same length. Values made of a single character
+/// get no suggestion, as they are one edit away from any single-letter section.
+fn suggest_section(value: &str) -> Option<&'static str> {
+    reverse_all::<Section>()
+        .map(|v| v.value())
+        .filter(|v| edit_distance(value, v) == 1 && value.chars().count() > 1)
+        .max_by_key(|v| v.len())
+}
+
+/// Levenshtein distance between `a` and `b`.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
         }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+/// Reads a single section, e.g. `mmm`, which must be written exactly as in a
+/// format.
+impl FromStr for Section {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all::<Section>()
+            .find(|v| v.value() == s)
+            .ok_or_else(|| FormatError::UnknownToken {
+                token: s.to_string(),
+                position: 0,
+                suggestion: suggest_section(s),
+            })
+    }
+}
+
+/// Reads a single separator, e.g. `/`.
+impl FromStr for Separator {
+    type Err = FormatError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        all::<Separator>()
+            .find(|v| v.value() == s)
+            .ok_or_else(|| FormatError::UnknownToken {
+                token: s.to_string(),
+                position: 0,
+                suggestion: None,
+            })
     }
 }
 
 impl Section {
-    fn value(&self) -> &str {
+    /// Returns the text that stands for this section in a format, e.g. `yyyy`.
+    pub const fn value(&self) -> &'static str {
         match *self {
             Self::YY => "yy",
             Self::YYYY => "yyyy",
+            Self::YYYYY => "yyyyy",
+            Self::FY => "fy",
+            Self::CC => "cc",
+            Self::C => "c",
+            Self::LEAP => "leap",
             Self::M => "m",
             Self::MM => "mm",
             Self::MMM => "mmm",
             Self::MMMM => "mmmm",
+            Self::MMMMM => "mmmmm",
+            Self::MMR => "mmr",
             Self::D => "d",
             Self::DD => "dd",
+            Self::DO => "do",
             Self::DDD => "ddd",
             Self::DDDD => "dddd",
+            Self::DDDDD => "ddddd",
+            Self::DIM => "dim",
+            Self::E => "e",
+            Self::EE => "ee",
+            Self::EUS => "eus",
+            Self::NTHDOW => "nthdow",
+            Self::Q => "q",
+            Self::QQ => "qq",
+            Self::HY => "hy",
+            Self::HYY => "hyy",
+            Self::SEASON => "season",
+            Self::W => "w",
+            Self::WW => "ww",
+            Self::WM => "wm",
+            Self::ISOYY => "isoyy",
+            Self::ISOYYYY => "isoyyyy",
+            Self::O => "o",
+            Self::OOO => "ooo",
+            Self::DOYR => "doyr",
+            Self::G => "g",
+            Self::GGGG => "gggg",
+            Self::JD => "jd",
+            Self::EPOCHDAYS => "epochdays",
+            Self::H => "h",
+            Self::HH => "hh",
+            Self::NN => "nn",
+            Self::SS => "ss",
+            Self::H12 => "h12",
+            Self::HH12 => "hh12",
+            Self::TT => "tt",
+            Self::T => "t",
+            Self::AA => "aa",
+            Self::A => "a",
+        }
+    }
+
+    /// Returns a short description of what this section displays, e.g.
+    /// `Four-digit year`.
+    pub const fn description(&self) -> &'static str {
+        match *self {
+            Self::YY => "Two-digit year",
+            Self::YYYY => "Four-digit year",
+            Self::YYYYY => "Signed year with at least five digits",
+            Self::FY => "Fiscal year",
+            Self::CC => "Century, counting from the 1st century",
+            Self::C => "Year without its last two digits",
+            Self::LEAP => "Whether the year is a leap year, either `true` or `false`",
+            Self::M => "One-digit month for months below 10",
+            Self::MM => "Two-digit month",
+            Self::MMM => "Three-letter abbreviation for month",
+            Self::MMMM => "Month spelled out in full",
+            Self::MMMMM => "Narrow name of the month",
+            Self::MMR => "Month in Roman numerals",
+            Self::D => "One-digit day of the month for days below 10",
+            Self::DD => "Two-digit day of the month",
+            Self::DO => "Day of the month with an ordinal suffix",
+            Self::DDD => "Three-letter abbreviation for day of the week",
+            Self::DDDD => "Day of the week spelled out in full",
+            Self::DDDDD => "Narrow name of the day of the week",
+            Self::DIM => "Number of days in the month",
+            Self::E => "ISO 8601 day of the week (1-7 from Monday)",
+            Self::EE => "Two-digit ISO 8601 day of the week (01-07 from Monday)",
+            Self::EUS => "Day of the week as in the US (1-7 from Sunday)",
+            Self::NTHDOW => "Occurrence of the day of the week in the month (1-5)",
+            Self::Q => "Quarter of the year",
+            Self::QQ => "Quarter of the year prefixed with a label",
+            Self::HY => "Half of the year",
+            Self::HYY => "Half of the year prefixed with `H`",
+            Self::SEASON => "Meteorological season",
+            Self::W => "ISO 8601 week of the year",
+            Self::WW => "Two-digit ISO 8601 week of the year",
+            Self::WM => "Week of the month",
+            Self::ISOYY => "Two-digit ISO 8601 week-numbering year",
+            Self::ISOYYYY => "Four-digit ISO 8601 week-numbering year",
+            Self::O => "Day of the year",
+            Self::OOO => "Three-digit day of the year",
+            Self::DOYR => "Number of days remaining in the year",
+            Self::G => "Abbreviated era",
+            Self::GGGG => "Era spelled out in full",
+            Self::JD => "Julian day number",
+            Self::EPOCHDAYS => "Days since the 1st of January 1970",
+            Self::H => "Hour of the day (0-23)",
+            Self::HH => "Two-digit hour of the day (00-23)",
+            Self::NN => "Two-digit minute",
+            Self::SS => "Two-digit second",
+            Self::H12 => "Hour of the day on a 12-hour clock (1-12)",
+            Self::HH12 => "Two-digit hour of the day on a 12-hour clock (01-12)",
+            Self::TT => "Half of the day, either `AM` or `PM`",
+            Self::T => "Half of the day abbreviated, either `A` or `P`",
+            Self::AA => "Lowercase half of the day, either `am` or `pm`",
+            Self::A => "Lowercase half of the day abbreviated, either `a` or `p`",
+        }
+    }
+
+    /// Returns the field of the date this section displays, if it's the
+    /// year, the month or the day of the month.
+    fn field(&self) -> Option<&'static str> {
+        match *self {
+            Self::YY | Self::YYYY | Self::YYYYY => Some("year"),
+            Self::M | Self::MM | Self::MMM | Self::MMMM | Self::MMMMM | Self::MMR => Some("month"),
+            Self::D | Self::DD | Self::DO => Some("day"),
+            _ => None,
         }
     }
 
-    fn format(&self, date: &NaiveDate) -> String {
+    /// Returns the usual longest length in bytes of this section, with the
+    /// English names and the specified `options`.
+    fn max_len(&self, options: &FormatOptions) -> usize {
+        match *self {
+            Self::MMM if options.month_period => 4,
+            Self::YYYY if options.sign_years => 5,
+            Self::MMMMM | Self::DDDDD | Self::E | Self::EUS | Self::NTHDOW => 1,
+            Self::Q | Self::HY | Self::WM | Self::T | Self::A => 1,
+            Self::HYY => 2,
+            Self::EE | Self::DIM | Self::CC | Self::C => 2,
+            Self::YY | Self::M | Self::MM | Self::D | Self::DD | Self::QQ | Self::W | Self::WW => 2,
+            Self::ISOYY => 2,
+            Self::G | Self::H | Self::HH | Self::NN | Self::SS | Self::H12 | Self::HH12 => 2,
+            Self::TT | Self::AA => 2,
+            Self::MMM | Self::DDD | Self::O | Self::OOO | Self::DOYR => 3,
+            Self::MMR => 4,
+            Self::YYYY | Self::ISOYYYY | Self::FY | Self::DO => 4,
+            Self::LEAP => 5,
+            Self::YYYYY | Self::SEASON => 6,
+            Self::JD => 7,
+            Self::EPOCHDAYS => 6,
+            Self::MMMM | Self::DDDD => 9,
+            Self::GGGG => 13,
+        }
+    }
+
+    fn write<W: Write>(
+        &self,
+        datetime: &NaiveDateTime,
+        names: &dyn NameProvider,
+        options: &FormatOptions,
+        out: &mut W,
+    ) -> fmt::Result {
+        let FormatOptions {
+            case,
+            pad,
+            week,
+            fiscal_start,
+            digits: _,
+            hemisphere,
+            month_period,
+            sign_years,
+        } = *options;
+        match self {
+            Section::YY => pad.write(datetime.year().rem_euclid(100), 2, out),
+            Section::YYYY => {
+                if sign_years && datetime.year() >= 0 {
+                    out.write_char('+')?;
+                }
+                write_year(datetime.year(), out)
+            }
+            Section::YYYYY => write!(out, "{:+06}", datetime.year()),
+            Section::CC => write!(out, "{}", century(datetime.year())),
+            Section::C => write!(out, "{}", datetime.year().div_euclid(100)),
+            Section::FY => write_year(fiscal_year(datetime, fiscal_start), out),
+            Section::LEAP => write!(out, "{}", is_leap_year(datetime.year())),
+            Section::M => write!(out, "{}", datetime.month()),
+            Section::MM => pad.write(datetime.month(), 2, out),
+            Section::MMM => {
+                let month = datetime.month() as u8;
+                let name = names.month_abbrev(month);
+                case.write(name, out)?;
+                if month_period && !name.ends_with('.') && name != names.month_wide(month) {
+                    out.write_char('.')?;
+                }
+                Ok(())
+            }
+            Section::MMMM => case.write(names.month_wide(datetime.month() as u8), out),
+            Section::MMMMM => case.write(names.month_narrow(datetime.month() as u8), out),
+            Section::D => write!(out, "{}", datetime.day()),
+            Section::DD => pad.write(datetime.day(), 2, out),
+            Section::DO => write!(
+                out,
+                "{}{}",
+                datetime.day(),
+                names.ordinal_suffix(datetime.day())
+            ),
+            Section::DDD => case.write(names.weekday_abbrev(weekday_index(datetime)), out),
+            Section::DDDD => case.write(names.weekday_wide(weekday_index(datetime)), out),
+            Section::DDDDD => case.write(names.weekday_narrow(weekday_index(datetime)), out),
+            Section::DIM => write!(out, "{}", days_in_month(datetime)),
+            Section::E => write!(out, "{}", week.weekday(datetime)),
+            Section::EE => write!(out, "{:0>2}", week.weekday(datetime)),
+            Section::EUS => write!(out, "{}", datetime.weekday().number_from_sunday()),
+            Section::NTHDOW => write!(out, "{}", weekday_of_month(datetime)),
+            Section::Q => write!(out, "{}", quarter(datetime)),
+            Section::QQ => write!(out, "{}{}", names.quarter_label(), quarter(datetime)),
+            Section::HY => write!(out, "{}", half_year(datetime)),
+            Section::HYY => write!(out, "H{}", half_year(datetime)),
+            Section::SEASON => case.write(hemisphere.season(datetime), out),
+            Section::W => write!(out, "{}", week.week(datetime)),
+            Section::WW => write!(out, "{:0>2}", week.week(datetime)),
+            Section::WM => write!(out, "{}", week.week_of_month(datetime)),
+            Section::ISOYY => pad.write(datetime.iso_week().year().rem_euclid(100), 2, out),
+            Section::ISOYYYY => write_year(datetime.iso_week().year(), out),
+            Section::O => write!(out, "{}", datetime.ordinal()),
+            Section::OOO => write!(out, "{:0>3}", datetime.ordinal()),
+            Section::DOYR => write!(out, "{}", days_remaining(datetime)),
+            Section::JD => write!(out, "{}", datetime.num_days_from_ce() + JULIAN_DAY_OFFSET),
+            Section::EPOCHDAYS => write!(out, "{}", datetime.num_days_from_ce() - UNIX_EPOCH_DAYS),
+            Section::H => write!(out, "{}", datetime.hour()),
+            Section::HH => write!(out, "{:0>2}", datetime.hour()),
+            Section::NN => write!(out, "{:0>2}", datetime.minute()),
+            Section::SS => write!(out, "{:0>2}", datetime.second()),
+            Section::H12 => write!(out, "{}", datetime.hour12().1),
+            Section::HH12 => write!(out, "{:0>2}", datetime.hour12().1),
+            Section::MMR
+            | Section::G
+            | Section::GGGG
+            | Section::TT
+            | Section::T
+            | Section::AA
+            | Section::A => out.write_str(
+                self.static_text(datetime)
+                    .expect("the section displays a static text"),
+            ),
+        }
+    }
+
+    /// Returns the text this section displays when it doesn't depend on the
+    /// options, nor on the names of the months and of the days of the week.
+    fn static_text(&self, datetime: &NaiveDateTime) -> Option<&'static str> {
+        let is_pm = datetime.hour12().0;
         match self {
-            Section::YY => format!("{:0>2}", date.year() % 100),
-            Section::YYYY => format!("{}", date.year()),
-            Section::M => format!("{}", date.month()),
-            Section::MM => format!("{:0>2}", date.month()),
-            Section::MMM => MONTHS_ABBREVIATED
-                .get(&(date.month() as u8))
-                .expect("month value found")
-                .to_string(),
-            Section::MMMM => MONTHS_WIDE
-                .get(&(date.month() as u8))
-                .expect("month value found")
-                .to_string(),
-            Section::D => format!("{}", date.day()),
-            Section::DD => format!("{:0>2}", date.day()),
-            Section::DDD => DAYS_ABBREVIATED
-                .get(&(date.weekday() as u8 + 1))
-                .expect("day value found")
-                .to_string(),
-            Section::DDDD => DAYS_WIDE
-                .get(&(date.weekday() as u8 + 1))
-                .expect("day value found")
-                .to_string(),
+            Section::MMR => Some(roman_month(datetime.month())),
+            Section::G => Some(if datetime.year() > 0 { "AD" } else { "BC" }),
+            Section::GGGG => Some(if datetime.year() > 0 {
+                "Anno Domini"
+            } else {
+                "Before Christ"
+            }),
+            Section::TT => Some(if is_pm { "PM" } else { "AM" }),
+            Section::T => Some(if is_pm { "P" } else { "A" }),
+            Section::AA => Some(if is_pm { "pm" } else { "am" }),
+            Section::A => Some(if is_pm { "p" } else { "a" }),
+            _ => None,
         }
     }
+
+    /// Returns the English name this section displays, if it displays one.
+    fn english_name(&self, datetime: &NaiveDateTime) -> Option<&'static str> {
+        let month = datetime.month() as u8;
+        let weekday = weekday_index(datetime);
+        let (names, key) = match self {
+            Section::MMM => (Locale::En.months_abbreviated(), month),
+            Section::MMMM => (Locale::En.months_wide(), month),
+            Section::MMMMM => (Locale::En.months_narrow(), month),
+            Section::DDD => (Locale::En.days_abbreviated(), weekday),
+            Section::DDDD => (Locale::En.days_wide(), weekday),
+            Section::DDDDD => (Locale::En.days_narrow(), weekday),
+            _ => return None,
+        };
+
+        names.get(&key).copied()
+    }
+}
+
+/// Returns the start of the day of `date`, which can be any type with a date,
+/// e.g. a `DateTime` in a time zone.
+fn at_midnight(date: &impl Datelike) -> NaiveDateTime {
+    NaiveDate::from_ymd_opt(date.year(), date.month(), date.day())
+        .expect("the date is valid")
+        .and_time(NaiveTime::MIN)
+}
+
+/// The Julian day number of the day before the 1st of January of year 1, which
+/// has `0` days from the common era.
+const JULIAN_DAY_OFFSET: i32 = 1_721_425;
+
+/// The days from the common era of the 1st of January 1970.
+const UNIX_EPOCH_DAYS: i32 = 719_163;
+
+/// Returns the day of the week of `date` as the key of the names of the days of
+/// the week, from `1` (Monday) to `7` (Sunday).
+fn weekday_index(date: &impl Datelike) -> u8 {
+    date.weekday().number_from_monday() as u8
+}
+
+/// Returns which occurrence of its day of the week `date` is in its month, e.g.
+/// `2` for the second Tuesday.
+fn weekday_of_month(date: &impl Datelike) -> u32 {
+    (date.day() - 1) / 7 + 1
+}
+
+/// Returns the number of days in the month of `date`, which is the day before
+/// the first day of the next month.
+fn days_in_month(date: &impl Datelike) -> u32 {
+    let (year, month) = match date.month() {
+        12 => (date.year() + 1, 1),
+        month => (date.year(), month + 1),
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .and_then(|v| v.pred_opt())
+        .map_or(31, |v| v.day())
+}
+
+/// Returns the number of days from `date` to the end of its year, so `0` on
+/// the 31st of December.
+fn days_remaining(date: &impl Datelike) -> u32 {
+    let days = if is_leap_year(date.year()) { 366 } else { 365 };
+    days - date.ordinal()
+}
+
+const ROMAN_MONTHS: [&str; 12] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII",
+];
+
+/// Returns the `month`, from 1 to 12, in Roman numerals.
+fn roman_month(month: u32) -> &'static str {
+    ROMAN_MONTHS[month as usize - 1]
+}
+
+/// Returns the fiscal year of `date`, when the fiscal year starts in the month
+/// `start`.
+fn fiscal_year(date: &impl Datelike, start: u8) -> i32 {
+    if start > 1 && date.month() >= start as u32 {
+        date.year() + 1
+    } else {
+        date.year()
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    NaiveDate::from_ymd_opt(year, 2, 29).is_some()
+}
+
+/// Returns the century of `year`, so that e.g. the 21st century goes from 2001
+/// to 2100.
+fn century(year: i32) -> i32 {
+    (year - 1).div_euclid(100) + 1
+}
+
+fn half_year(date: &impl Datelike) -> u32 {
+    (date.month() - 1) / 6 + 1
+}
+
+/// Writes the `year` padded with zeros to at least four digits, keeping the
+/// sign of a negative year in front of the padding, e.g. `-0044`.
+fn write_year<W: Write>(year: i32, out: &mut W) -> fmt::Result {
+    if year < 0 {
+        write!(out, "-{:0>4}", -year)
+    } else {
+        write!(out, "{:0>4}", year)
+    }
+}
+
+fn quarter(date: &impl Datelike) -> u32 {
+    (date.month() - 1) / 3 + 1
+}
+
+fn ordinal_suffix(day: u32) -> &'static str {
+    match (day % 10, day % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    }
 }
 
 impl Separator {
-    fn value(&self) -> &str {
+    /// Returns the text that stands for this separator in a format, e.g. `/`.
+    pub const fn value(&self) -> &'static str {
         match *self {
             Self::Slash => "/",
             Self::Period => ".",
             Self::Hyphen => "-",
             Self::Space => " ",
+            Self::Colon => ":",
+            Self::Comma => ",",
+            Self::Underscore => "_",
+            Self::At => "@",
         }
     }
-}
-
-static MONTHS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
-    1u8 => "Jan",
-    2u8 => "Feb",
-    3u8 => "Mar",
-    4u8 => "Apr",
-    5u8 => "May",
-    6u8 => "Jun",
-    7u8 => "Jul",
-    8u8 => "Aug",
-    9u8 => "Sep",
-    10u8 => "Oct",
-    11u8 => "Nov",
-    12u8 => "Dec",
-};
 
-static MONTHS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
-    1u8 => "January",
-    2u8 => "February",
-    3u8 => "March",
-    4u8 => "April",
-    5u8 => "May",
-    6u8 => "June",
-    7u8 => "July",
-    8u8 => "August",
-    9u8 => "September",
-    10u8 => "October",
-    11u8 => "November",
-    12u8 => "December",
-};
+    /// Returns a short description of this separator, e.g. `Oblique stroke
+    /// (slash)`.
+    pub const fn description(&self) -> &'static str {
+        match *self {
+            Self::Slash => "Oblique stroke (slash)",
+            Self::Period => "Full stop, dot or point (period)",
+            Self::Hyphen => "Hyphen (dash)",
+            Self::Space => "Space",
+            Self::Colon => "Colon",
+            Self::Comma => "Comma",
+            Self::Underscore => "Low line (underscore)",
+            Self::At => "Commercial at",
+        }
+    }
+}
 
-static DAYS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
-    1u8 => "Mon",
-    2u8 => "Tue",
-    3u8 => "Wed",
-    4u8 => "Thu",
-    5u8 => "Fri",
-    6u8 => "Sat",
-    7u8 => "Sun",
-};
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-static DAYS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
-    1u8 => "Monday",
-    2u8 => "Tuesday",
-    3u8 => "Wednesday",
-    4u8 => "Thursday",
-    5u8 => "Friday",
-    6u8 => "Saturday",
-    7u8 => "Sunday",
-};
+    #[test]
+    fn tokens_are_the_sections_and_separators() {
+        let parts: Vec<FormatPart> = all::<Section>()
+            .map(FormatPart::Section)
+            .chain(all::<Separator>().map(FormatPart::Separator))
+            .collect();
+        assert_eq!(TOKENS.len(), parts.len());
+        for part in parts {
+            assert_eq!(TOKENS.get(part.value()), Some(&part));
+        }
+        assert_eq!(TOKENS.keys().map(|v| v.len()).max(), Some(LONGEST_TOKEN));
+    }
+}
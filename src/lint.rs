@@ -0,0 +1,146 @@
+use alloc::{vec, vec::Vec};
+use core::fmt;
+
+use crate::{Format, FormatError, FormatPart, Section, Separator};
+
+/// A part of a valid format that is likely a mistake, see [`lint`]. The
+/// `position` is the byte offset of the part in the format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Lint {
+    /// `mm` is next to the hour, so it was probably meant as the minutes,
+    /// which are `nn`.
+    MonthLooksLikeMinutes { position: usize },
+    /// `yy` leaves out the century, so the year is ambiguous when it is read
+    /// back.
+    TwoDigitYear { position: usize },
+    /// A number follows another one without anything in between, so where the
+    /// first one ends is ambiguous when they are read back.
+    AdjacentNumbers { position: usize },
+    /// The format is not valid, so it can't be checked any further.
+    InvalidPattern(FormatError),
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::MonthLooksLikeMinutes { position } => write!(
+                f,
+                "The month at position {} is next to the hour, the minutes are \"nn\"",
+                position
+            ),
+            Self::TwoDigitYear { position } => {
+                write!(
+                    f,
+                    "The two-digit year at position {} is ambiguous",
+                    position
+                )
+            }
+            Self::AdjacentNumbers { position } => write!(
+                f,
+                "The number at position {} follows another number without a separator",
+                position
+            ),
+            Self::InvalidPattern(error) => write!(f, "The format is not valid: {}", error),
+        }
+    }
+}
+
+/// Returns the parts of `format` that are valid but likely a mistake, e.g.
+/// `yymmdd` gets a [`Lint::TwoDigitYear`] and two [`Lint::AdjacentNumbers`].
+/// A format that is not valid only gets a [`Lint::InvalidPattern`] with the
+/// error [`Format::parse`] fails with.
+pub fn lint(format: &str) -> Vec<Lint> {
+    let format = match Format::parse(format) {
+        Ok(v) => v,
+        Err(e) => return vec![Lint::InvalidPattern(e)],
+    };
+    let sections: Vec<Option<Section>> = format
+        .parts
+        .iter()
+        .map(|v| match v {
+            FormatPart::Section(section) => Some(*section),
+            _ => None,
+        })
+        .collect();
+    let mut lints = Vec::new();
+    for (index, section) in sections.iter().enumerate() {
+        let Some(section) = section else {
+            continue;
+        };
+        let position = format.ranges[index].start;
+        if *section == Section::MM && is_next_to_hour(&format.parts, index) {
+            lints.push(Lint::MonthLooksLikeMinutes { position });
+        }
+        if *section == Section::YY {
+            lints.push(Lint::TwoDigitYear { position });
+        }
+        let previous = index.checked_sub(1).and_then(|v| sections[v]);
+        if is_number(section) && previous.as_ref().is_some_and(is_number) {
+            lints.push(Lint::AdjacentNumbers { position });
+        }
+    }
+
+    lints
+}
+
+/// Returns whether the part at `index` is next to an hour, either directly or
+/// across a colon.
+fn is_next_to_hour(parts: &[FormatPart], index: usize) -> bool {
+    let is_hour = |part: Option<&FormatPart>| {
+        matches!(
+            part,
+            Some(FormatPart::Section(
+                Section::H | Section::HH | Section::H12 | Section::HH12
+            ))
+        )
+    };
+    let is_colon =
+        |part: Option<&FormatPart>| matches!(part, Some(FormatPart::Separator(Separator::Colon)));
+    let at = |offset: isize| parts.get(index.checked_add_signed(offset)?);
+
+    is_hour(at(-1))
+        || is_hour(at(1))
+        || (is_colon(at(-1)) && is_hour(at(-2)))
+        || (is_colon(at(1)) && is_hour(at(2)))
+}
+
+/// Returns whether the section displays a number, along with the suffix of
+/// `do`.
+fn is_number(section: &Section) -> bool {
+    matches!(
+        section,
+        Section::YY
+            | Section::YYYY
+            | Section::FY
+            | Section::CC
+            | Section::C
+            | Section::M
+            | Section::MM
+            | Section::D
+            | Section::DD
+            | Section::DO
+            | Section::DIM
+            | Section::E
+            | Section::EE
+            | Section::EUS
+            | Section::NTHDOW
+            | Section::Q
+            | Section::HY
+            | Section::W
+            | Section::WW
+            | Section::WM
+            | Section::ISOYY
+            | Section::ISOYYYY
+            | Section::O
+            | Section::OOO
+            | Section::DOYR
+            | Section::JD
+            | Section::EPOCHDAYS
+            | Section::H
+            | Section::HH
+            | Section::NN
+            | Section::SS
+            | Section::H12
+            | Section::HH12
+    )
+}
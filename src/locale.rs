@@ -0,0 +1,561 @@
+use alloc::string::{String, ToString};
+
+use chrono::{Datelike, Weekday};
+use phf::phf_ordered_map;
+
+use crate::weekday_index;
+
+/// The languages the names of the months and of the days of the week can be
+/// displayed in. The names are taken from
+/// [The Unicode Common Locale Data Repository](https://github.com/unicode-org/cldr-json/tree/main/cldr-json/cldr-dates-modern/main).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    /// English
+    #[default]
+    En,
+    /// Italian
+    It,
+    /// French
+    Fr,
+    /// German
+    De,
+}
+
+/// How long the names of the months and of the days of the week are.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NameStyle {
+    /// e.g. `Mar`, `Fri`
+    Abbreviated,
+    /// e.g. `March`, `Friday`
+    Wide,
+    /// e.g. `M`, `F`
+    Narrow,
+}
+
+/// Returns the English names of the days of the week in order, starting from
+/// `first_day`, e.g. `Sun` to `Sat` for a calendar whose weeks start on
+/// Sunday.
+pub fn weekday_headers(style: NameStyle, first_day: Weekday) -> [String; 7] {
+    let days = Locale::En.days(style);
+    let first = first_day.num_days_from_monday() as usize;
+    core::array::from_fn(|i| {
+        let d = ((first + i) % 7 + 1) as u8;
+        days.get(&d).expect("day value found").to_string()
+    })
+}
+
+/// Returns the names of the months in `locale` in order, from January to
+/// December.
+pub fn month_names(style: NameStyle, locale: Locale) -> [String; 12] {
+    let months = locale.months(style);
+    core::array::from_fn(|i| {
+        let m = (i + 1) as u8;
+        months.get(&m).expect("month value found").to_string()
+    })
+}
+
+/// Returns the name of the day of the week of `date` in `locale`, e.g.
+/// `Saturday`.
+pub fn weekday_name(date: &impl Datelike, style: NameStyle, locale: Locale) -> String {
+    locale
+        .days(style)
+        .get(&weekday_index(date))
+        .expect("day value found")
+        .to_string()
+}
+
+/// Returns the name of the month of `date` in `locale`, e.g. `March`.
+pub fn month_name(date: &impl Datelike, style: NameStyle, locale: Locale) -> String {
+    locale
+        .months(style)
+        .get(&(date.month() as u8))
+        .expect("month value found")
+        .to_string()
+}
+
+/// Provides the names of the months and of the days of the week, so that they
+/// can come from any source, e.g. a full CLDR data set.
+///
+/// Months go from `1` (January) to `12` (December), days of the week from `1`
+/// (Monday) to `7` (Sunday).
+pub trait NameProvider {
+    /// Returns the full name of the month, e.g. `March`.
+    fn month_wide(&self, m: u8) -> &str;
+    /// Returns the abbreviated name of the month, e.g. `Mar`.
+    fn month_abbrev(&self, m: u8) -> &str;
+    /// Returns the full name of the day of the week, e.g. `Friday`.
+    fn weekday_wide(&self, d: u8) -> &str;
+    /// Returns the abbreviated name of the day of the week, e.g. `Fri`.
+    fn weekday_abbrev(&self, d: u8) -> &str;
+    /// Returns the narrow name of the month, e.g. `M`. By default this is the
+    /// first character of the full name.
+    fn month_narrow(&self, m: u8) -> &str {
+        first_char(self.month_wide(m))
+    }
+    /// Returns the narrow name of the day of the week, e.g. `F`. By default
+    /// this is the first character of the full name.
+    fn weekday_narrow(&self, d: u8) -> &str {
+        first_char(self.weekday_wide(d))
+    }
+    /// Returns the suffix displayed after the day of the month by `do`, e.g.
+    /// `nd` for `2`. By default this is the English suffix.
+    fn ordinal_suffix(&self, day: u32) -> &str {
+        crate::ordinal_suffix(day)
+    }
+    /// Returns the label displayed before the quarter by `qq`, e.g. `Q` for
+    /// `Q1`. By default this is `Q`.
+    fn quarter_label(&self) -> &str {
+        "Q"
+    }
+}
+
+fn first_char(value: &str) -> &str {
+    &value[..value.chars().next().map_or(0, char::len_utf8)]
+}
+
+/// The English names used by default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct EnglishNames;
+
+impl NameProvider for EnglishNames {
+    fn month_wide(&self, m: u8) -> &str {
+        Locale::En.month_wide(m)
+    }
+
+    fn month_abbrev(&self, m: u8) -> &str {
+        Locale::En.month_abbrev(m)
+    }
+
+    fn weekday_wide(&self, d: u8) -> &str {
+        Locale::En.weekday_wide(d)
+    }
+
+    fn weekday_abbrev(&self, d: u8) -> &str {
+        Locale::En.weekday_abbrev(d)
+    }
+
+    fn month_narrow(&self, m: u8) -> &str {
+        Locale::En.month_narrow(m)
+    }
+
+    fn weekday_narrow(&self, d: u8) -> &str {
+        Locale::En.weekday_narrow(d)
+    }
+}
+
+impl NameProvider for Locale {
+    fn month_wide(&self, m: u8) -> &str {
+        self.months_wide().get(&m).expect("month value found")
+    }
+
+    fn month_abbrev(&self, m: u8) -> &str {
+        self.months_abbreviated()
+            .get(&m)
+            .expect("month value found")
+    }
+
+    fn weekday_wide(&self, d: u8) -> &str {
+        self.days_wide().get(&d).expect("day value found")
+    }
+
+    fn weekday_abbrev(&self, d: u8) -> &str {
+        self.days_abbreviated().get(&d).expect("day value found")
+    }
+
+    fn month_narrow(&self, m: u8) -> &str {
+        self.months_narrow().get(&m).expect("month value found")
+    }
+
+    fn weekday_narrow(&self, d: u8) -> &str {
+        self.days_narrow().get(&d).expect("day value found")
+    }
+
+    fn ordinal_suffix(&self, day: u32) -> &str {
+        match self {
+            Self::En => crate::ordinal_suffix(day),
+            Self::It => "º",
+            Self::Fr if day == 1 => "er",
+            Self::Fr => "e",
+            Self::De => ".",
+        }
+    }
+
+    fn quarter_label(&self) -> &str {
+        match self {
+            Self::En | Self::De => "Q",
+            Self::It | Self::Fr => "T",
+        }
+    }
+}
+
+impl Locale {
+    pub(crate) fn months(&self, style: NameStyle) -> &'static phf::OrderedMap<u8, &'static str> {
+        match style {
+            NameStyle::Abbreviated => self.months_abbreviated(),
+            NameStyle::Wide => self.months_wide(),
+            NameStyle::Narrow => self.months_narrow(),
+        }
+    }
+
+    pub(crate) fn days(&self, style: NameStyle) -> &'static phf::OrderedMap<u8, &'static str> {
+        match style {
+            NameStyle::Abbreviated => self.days_abbreviated(),
+            NameStyle::Wide => self.days_wide(),
+            NameStyle::Narrow => self.days_narrow(),
+        }
+    }
+
+    pub(crate) fn months_abbreviated(&self) -> &'static phf::OrderedMap<u8, &'static str> {
+        match self {
+            Self::En => &EN_MONTHS_ABBREVIATED,
+            Self::It => &IT_MONTHS_ABBREVIATED,
+            Self::Fr => &FR_MONTHS_ABBREVIATED,
+            Self::De => &DE_MONTHS_ABBREVIATED,
+        }
+    }
+
+    pub(crate) fn months_wide(&self) -> &'static phf::OrderedMap<u8, &'static str> {
+        match self {
+            Self::En => &EN_MONTHS_WIDE,
+            Self::It => &IT_MONTHS_WIDE,
+            Self::Fr => &FR_MONTHS_WIDE,
+            Self::De => &DE_MONTHS_WIDE,
+        }
+    }
+
+    pub(crate) fn months_narrow(&self) -> &'static phf::OrderedMap<u8, &'static str> {
+        match self {
+            Self::En => &EN_MONTHS_NARROW,
+            Self::It => &IT_MONTHS_NARROW,
+            Self::Fr => &FR_MONTHS_NARROW,
+            Self::De => &DE_MONTHS_NARROW,
+        }
+    }
+
+    pub(crate) fn days_abbreviated(&self) -> &'static phf::OrderedMap<u8, &'static str> {
+        match self {
+            Self::En => &EN_DAYS_ABBREVIATED,
+            Self::It => &IT_DAYS_ABBREVIATED,
+            Self::Fr => &FR_DAYS_ABBREVIATED,
+            Self::De => &DE_DAYS_ABBREVIATED,
+        }
+    }
+
+    pub(crate) fn days_wide(&self) -> &'static phf::OrderedMap<u8, &'static str> {
+        match self {
+            Self::En => &EN_DAYS_WIDE,
+            Self::It => &IT_DAYS_WIDE,
+            Self::Fr => &FR_DAYS_WIDE,
+            Self::De => &DE_DAYS_WIDE,
+        }
+    }
+
+    pub(crate) fn days_narrow(&self) -> &'static phf::OrderedMap<u8, &'static str> {
+        match self {
+            Self::En => &EN_DAYS_NARROW,
+            Self::It => &IT_DAYS_NARROW,
+            Self::Fr => &FR_DAYS_NARROW,
+            Self::De => &DE_DAYS_NARROW,
+        }
+    }
+}
+
+static EN_MONTHS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Jan",
+    2u8 => "Feb",
+    3u8 => "Mar",
+    4u8 => "Apr",
+    5u8 => "May",
+    6u8 => "Jun",
+    7u8 => "Jul",
+    8u8 => "Aug",
+    9u8 => "Sep",
+    10u8 => "Oct",
+    11u8 => "Nov",
+    12u8 => "Dec",
+};
+
+static EN_MONTHS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "January",
+    2u8 => "February",
+    3u8 => "March",
+    4u8 => "April",
+    5u8 => "May",
+    6u8 => "June",
+    7u8 => "July",
+    8u8 => "August",
+    9u8 => "September",
+    10u8 => "October",
+    11u8 => "November",
+    12u8 => "December",
+};
+
+static EN_MONTHS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "J",
+    2u8 => "F",
+    3u8 => "M",
+    4u8 => "A",
+    5u8 => "M",
+    6u8 => "J",
+    7u8 => "J",
+    8u8 => "A",
+    9u8 => "S",
+    10u8 => "O",
+    11u8 => "N",
+    12u8 => "D",
+};
+
+static EN_DAYS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Mon",
+    2u8 => "Tue",
+    3u8 => "Wed",
+    4u8 => "Thu",
+    5u8 => "Fri",
+    6u8 => "Sat",
+    7u8 => "Sun",
+};
+
+static EN_DAYS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Monday",
+    2u8 => "Tuesday",
+    3u8 => "Wednesday",
+    4u8 => "Thursday",
+    5u8 => "Friday",
+    6u8 => "Saturday",
+    7u8 => "Sunday",
+};
+
+static EN_DAYS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "M",
+    2u8 => "T",
+    3u8 => "W",
+    4u8 => "T",
+    5u8 => "F",
+    6u8 => "S",
+    7u8 => "S",
+};
+
+static IT_MONTHS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "gen",
+    2u8 => "feb",
+    3u8 => "mar",
+    4u8 => "apr",
+    5u8 => "mag",
+    6u8 => "giu",
+    7u8 => "lug",
+    8u8 => "ago",
+    9u8 => "set",
+    10u8 => "ott",
+    11u8 => "nov",
+    12u8 => "dic",
+};
+
+static IT_MONTHS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "gennaio",
+    2u8 => "febbraio",
+    3u8 => "marzo",
+    4u8 => "aprile",
+    5u8 => "maggio",
+    6u8 => "giugno",
+    7u8 => "luglio",
+    8u8 => "agosto",
+    9u8 => "settembre",
+    10u8 => "ottobre",
+    11u8 => "novembre",
+    12u8 => "dicembre",
+};
+
+static IT_MONTHS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "G",
+    2u8 => "F",
+    3u8 => "M",
+    4u8 => "A",
+    5u8 => "M",
+    6u8 => "G",
+    7u8 => "L",
+    8u8 => "A",
+    9u8 => "S",
+    10u8 => "O",
+    11u8 => "N",
+    12u8 => "D",
+};
+
+static IT_DAYS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "lun",
+    2u8 => "mar",
+    3u8 => "mer",
+    4u8 => "gio",
+    5u8 => "ven",
+    6u8 => "sab",
+    7u8 => "dom",
+};
+
+static IT_DAYS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "lunedì",
+    2u8 => "martedì",
+    3u8 => "mercoledì",
+    4u8 => "giovedì",
+    5u8 => "venerdì",
+    6u8 => "sabato",
+    7u8 => "domenica",
+};
+
+static IT_DAYS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "L",
+    2u8 => "M",
+    3u8 => "M",
+    4u8 => "G",
+    5u8 => "V",
+    6u8 => "S",
+    7u8 => "D",
+};
+
+static FR_MONTHS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "janv.",
+    2u8 => "févr.",
+    3u8 => "mars",
+    4u8 => "avr.",
+    5u8 => "mai",
+    6u8 => "juin",
+    7u8 => "juil.",
+    8u8 => "août",
+    9u8 => "sept.",
+    10u8 => "oct.",
+    11u8 => "nov.",
+    12u8 => "déc.",
+};
+
+static FR_MONTHS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "janvier",
+    2u8 => "février",
+    3u8 => "mars",
+    4u8 => "avril",
+    5u8 => "mai",
+    6u8 => "juin",
+    7u8 => "juillet",
+    8u8 => "août",
+    9u8 => "septembre",
+    10u8 => "octobre",
+    11u8 => "novembre",
+    12u8 => "décembre",
+};
+
+static FR_MONTHS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "J",
+    2u8 => "F",
+    3u8 => "M",
+    4u8 => "A",
+    5u8 => "M",
+    6u8 => "J",
+    7u8 => "J",
+    8u8 => "A",
+    9u8 => "S",
+    10u8 => "O",
+    11u8 => "N",
+    12u8 => "D",
+};
+
+static FR_DAYS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "lun.",
+    2u8 => "mar.",
+    3u8 => "mer.",
+    4u8 => "jeu.",
+    5u8 => "ven.",
+    6u8 => "sam.",
+    7u8 => "dim.",
+};
+
+static FR_DAYS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "lundi",
+    2u8 => "mardi",
+    3u8 => "mercredi",
+    4u8 => "jeudi",
+    5u8 => "vendredi",
+    6u8 => "samedi",
+    7u8 => "dimanche",
+};
+
+static FR_DAYS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "L",
+    2u8 => "M",
+    3u8 => "M",
+    4u8 => "J",
+    5u8 => "V",
+    6u8 => "S",
+    7u8 => "D",
+};
+
+static DE_MONTHS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Jan.",
+    2u8 => "Feb.",
+    3u8 => "März",
+    4u8 => "Apr.",
+    5u8 => "Mai",
+    6u8 => "Juni",
+    7u8 => "Juli",
+    8u8 => "Aug.",
+    9u8 => "Sept.",
+    10u8 => "Okt.",
+    11u8 => "Nov.",
+    12u8 => "Dez.",
+};
+
+static DE_MONTHS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Januar",
+    2u8 => "Februar",
+    3u8 => "März",
+    4u8 => "April",
+    5u8 => "Mai",
+    6u8 => "Juni",
+    7u8 => "Juli",
+    8u8 => "August",
+    9u8 => "September",
+    10u8 => "Oktober",
+    11u8 => "November",
+    12u8 => "Dezember",
+};
+
+static DE_MONTHS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "J",
+    2u8 => "F",
+    3u8 => "M",
+    4u8 => "A",
+    5u8 => "M",
+    6u8 => "J",
+    7u8 => "J",
+    8u8 => "A",
+    9u8 => "S",
+    10u8 => "O",
+    11u8 => "N",
+    12u8 => "D",
+};
+
+static DE_DAYS_ABBREVIATED: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Mo.",
+    2u8 => "Di.",
+    3u8 => "Mi.",
+    4u8 => "Do.",
+    5u8 => "Fr.",
+    6u8 => "Sa.",
+    7u8 => "So.",
+};
+
+static DE_DAYS_WIDE: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "Montag",
+    2u8 => "Dienstag",
+    3u8 => "Mittwoch",
+    4u8 => "Donnerstag",
+    5u8 => "Freitag",
+    6u8 => "Samstag",
+    7u8 => "Sonntag",
+};
+
+static DE_DAYS_NARROW: phf::OrderedMap<u8, &str> = phf_ordered_map! {
+    1u8 => "M",
+    2u8 => "D",
+    3u8 => "M",
+    4u8 => "D",
+    5u8 => "F",
+    6u8 => "S",
+    7u8 => "S",
+};
@@ -0,0 +1,12 @@
+/// Same as [`format`](fn@crate::format), written like `format!`, e.g.
+/// `date_format!(date, "yyyy-mm-dd")`. The date is taken by reference, so it
+/// can be any expression.
+///
+/// The format is only checked when the macro runs, use `checked_format!` from
+/// the `tommaso_date_formatter_macros` crate to check it at compile time.
+#[macro_export]
+macro_rules! date_format {
+    ($date:expr, $format:expr $(,)?) => {
+        $crate::format(&$date, $format)
+    };
+}
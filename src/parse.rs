@@ -0,0 +1,663 @@
+use alloc::string::ToString;
+use core::fmt;
+
+use chrono::{Datelike, NaiveDate, NaiveTime, Weekday};
+
+use crate::{
+    century, days_in_month, days_remaining, half_year, is_leap_year, ordinal_suffix, quarter,
+    weekday_index, weekday_of_month, Format, FormatError, FormatPart, Locale, Section, WeekConfig,
+    JULIAN_DAY_OFFSET, ROMAN_MONTHS, UNIX_EPOCH_DAYS,
+};
+
+/**
+Returns the date represented by `input`, which must be written in the specified
+`format`. This is the inverse of [`format`](fn@crate::format), so the same sections
+and separators are supported.
+
+The format must contain enough information to build a date: a year (`yy` or
+`yyyy`) and either the month and the day of the month, or the day of the year.
+Without a year, the week-numbering year (`isoyyyy`), the week (`ww`) and the
+day of the week (`e`) are enough.
+A Julian day (`jd`) or a number of days since 1970 (`epochdays`) is enough on
+its own.
+Any other section is read and checked against the resulting date, e.g. parsing
+`Mon 04/03/2024` with `ddd dd/mm/yyyy` fails because the 4th of March 2024 is a
+Monday.
+
+Time sections are read and checked to make a valid time, which is then
+discarded. The names of the months and of the days of the week are read in
+English. Narrow names are not unique, so `mmmmm` and `ddddd` can't be read, and
+neither can `fy` and `season`, which depend on how the date was displayed.
+
+A two-digit year is placed in the range 1969 to 2068, so `68` is read as `2068`
+and `69` as `1969`, unless the format also has the century, e.g. `cyy`. Use
+[`parse_with_pivot`] to choose another range.
+*/
+pub fn parse(input: &str, format: &str) -> Result<NaiveDate, FormatError> {
+    Format::parse(format)?.parse_date(input)
+}
+
+/// Same as [`parse`], but two-digit years are placed in the hundred years
+/// starting from `pivot`.
+pub fn parse_with_pivot(
+    input: &str,
+    format: &str,
+    pivot: YearPivot,
+) -> Result<NaiveDate, FormatError> {
+    Format::parse(format)?.parse_date_with(input, pivot)
+}
+
+/// Same as [`parse`], but returns `None` instead of the reason why `input`
+/// can't be read, for when only knowing whether it's valid matters. The reason
+/// is never written, so no error message is allocated.
+pub fn try_parse(input: &str, format: &str) -> Option<NaiveDate> {
+    Format::parse(format).ok()?.try_parse_date(input)
+}
+
+/// Returns whether the `date` displayed in the specified `format` is read back
+/// as the same date, i.e. whether the format keeps enough information about
+/// the date. Formats that leave out part of the date, e.g. `mmm` or `dd/mm`,
+/// never do, and neither do invalid formats.
+pub fn roundtrip_ok(date: &NaiveDate, format: &str) -> bool {
+    crate::format(date, format).is_ok_and(|v| parse(&v, format) == Ok(*date))
+}
+
+impl Format {
+    /// Same as [`parse`], using this format.
+    pub fn parse_date(&self, input: &str) -> Result<NaiveDate, FormatError> {
+        self.parse_date_with(input, YearPivot::default())
+    }
+
+    /// Same as [`parse_with_pivot`], using this format.
+    pub fn parse_date_with(&self, input: &str, pivot: YearPivot) -> Result<NaiveDate, FormatError> {
+        self.read_fields(input)
+            .map_err(|(position, reason)| FormatError::InvalidInput {
+                position,
+                reason: reason.to_string(),
+            })?
+            .resolve(pivot)
+    }
+
+    /// Same as [`try_parse`], using this format.
+    pub fn try_parse_date(&self, input: &str) -> Option<NaiveDate> {
+        self.read_fields(input)
+            .ok()?
+            .resolve(YearPivot::default())
+            .ok()
+    }
+
+    /// Reads the values of the sections from `input`, returning the position
+    /// and the reason of the first mismatch.
+    fn read_fields<'a>(&'a self, input: &str) -> Result<Fields, (usize, Reason<'a>)> {
+        let mut fields = Fields::default();
+        let mut rest = input;
+        for (index, part) in self.parts.iter().enumerate() {
+            let position = input.len() - rest.len();
+            rest = match part {
+                FormatPart::Section(section) => {
+                    let adjacent =
+                        matches!(self.parts.get(index + 1), Some(FormatPart::Section(_)));
+                    fields
+                        .read(section, rest, adjacent)
+                        .map_err(|reason| (position, reason))?
+                }
+                FormatPart::Separator(separator) => rest
+                    .strip_prefix(separator.value())
+                    .ok_or((position, Reason::Expected(separator.value())))?,
+                FormatPart::Literal(text) => rest
+                    .strip_prefix(text.as_str())
+                    .ok_or((position, Reason::Expected(text)))?,
+            };
+        }
+        if !rest.is_empty() {
+            return Err((input.len() - rest.len(), Reason::Other("Unexpected input")));
+        }
+
+        Ok(fields)
+    }
+}
+
+/// Why the input doesn't match the format, only written into a message when
+/// the error is returned.
+#[derive(Debug)]
+enum Reason<'a> {
+    Expected(&'a str),
+    ExpectedEither(&'static str, &'static str),
+    ExpectedName(&'static str),
+    Conflicting(&'static str),
+    Other(&'static str),
+}
+
+impl fmt::Display for Reason<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Expected(text) => write!(f, "Expected \"{}\"", text),
+            Self::ExpectedEither(yes, no) => write!(f, "Expected \"{}\" or \"{}\"", yes, no),
+            Self::ExpectedName(name) => write!(f, "Expected the name of a {}", name),
+            Self::Conflicting(name) => write!(f, "Conflicting values for the {}", name),
+            Self::Other(reason) => f.write_str(reason),
+        }
+    }
+}
+
+/// The first year of the hundred years two-digit years are placed in, e.g.
+/// with `1969`, the default, `68` is read as `2068` and `69` as `1969`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct YearPivot {
+    pub first_year: i32,
+}
+
+impl Default for YearPivot {
+    fn default() -> Self {
+        Self { first_year: 1969 }
+    }
+}
+
+impl YearPivot {
+    /// Returns the year ending with `year_of_century` in the hundred years,
+    /// or `None` if it doesn't fit in an `i32`.
+    fn year(&self, year_of_century: u32) -> Option<i32> {
+        let year = self
+            .first_year
+            .checked_sub(self.first_year.rem_euclid(100))?
+            .checked_add(year_of_century as i32)?;
+        if year < self.first_year {
+            year.checked_add(100)
+        } else {
+            Some(year)
+        }
+    }
+}
+
+/// The values read from the input, before they are combined into a date.
+#[derive(Debug, Default)]
+struct Fields {
+    year: Option<i32>,
+    year_of_century: Option<u32>,
+    century: Option<i32>,
+    year_hundreds: Option<i32>,
+    is_leap_year: Option<bool>,
+    month: Option<u32>,
+    day: Option<u32>,
+    ordinal: Option<u32>,
+    weekday: Option<u32>,
+    weekday_of_month: Option<u32>,
+    days_in_month: Option<u32>,
+    days_remaining: Option<u32>,
+    quarter: Option<u32>,
+    half_year: Option<u32>,
+    iso_week: Option<u32>,
+    week_of_month: Option<u32>,
+    iso_year: Option<i32>,
+    iso_year_of_century: Option<u32>,
+    is_ad: Option<bool>,
+    days_from_ce: Option<i64>,
+    hour: Option<u32>,
+    minute: Option<u32>,
+    second: Option<u32>,
+    hour12: Option<u32>,
+    is_pm: Option<bool>,
+}
+
+impl Fields {
+    /// Reads the value of `section` from the start of `input` and returns the
+    /// rest of the input. When the next part is `adjacent` numbers with a
+    /// variable length are limited to their usual length, so that e.g.
+    /// `yyyymmdd` can be read.
+    fn read<'a>(
+        &mut self,
+        section: &Section,
+        input: &'a str,
+        adjacent: bool,
+    ) -> Result<&'a str, Reason<'static>> {
+        let rest = match section {
+            Section::YY => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.year_of_century, value, "year")?;
+                rest
+            }
+            Section::YYYY => {
+                // The `+` of `FormatOptions::sign_years`.
+                let input = input.strip_prefix('+').unwrap_or(input);
+                let (value, rest) = read_signed(input, if adjacent { 4 } else { 9 })?;
+                set(&mut self.year, value as i32, "year")?;
+                rest
+            }
+            Section::LEAP => {
+                let (value, rest) = read_either(input, "true", "false")?;
+                set(&mut self.is_leap_year, value, "leap year")?;
+                rest
+            }
+            Section::YYYYY => {
+                let (negative, digits) = if let Some(digits) = input.strip_prefix('+') {
+                    (false, digits)
+                } else if let Some(digits) = input.strip_prefix('-') {
+                    (true, digits)
+                } else {
+                    return Err(Reason::ExpectedEither("+", "-"));
+                };
+                let (value, rest) = read_number(digits, if adjacent { 5 } else { 9 })?;
+                let value = value as i32;
+                set(
+                    &mut self.year,
+                    if negative { -value } else { value },
+                    "year",
+                )?;
+                rest
+            }
+            Section::CC | Section::C => {
+                let (value, rest) = read_signed(input, if adjacent { 2 } else { 7 })?;
+                if *section == Section::CC {
+                    set(&mut self.century, value as i32, "century")?;
+                } else {
+                    set(&mut self.year_hundreds, value as i32, "century")?;
+                }
+                rest
+            }
+            Section::M | Section::MM => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.month, value, "month")?;
+                rest
+            }
+            Section::MMM => {
+                let (value, rest) = read_name(input, Locale::En.months_abbreviated(), "month")?;
+                set(&mut self.month, value, "month")?;
+                rest
+            }
+            Section::MMMM => {
+                let (value, rest) = read_name(input, Locale::En.months_wide(), "month")?;
+                set(&mut self.month, value, "month")?;
+                rest
+            }
+            Section::MMR => {
+                let (index, numeral) = ROMAN_MONTHS
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, v)| input.starts_with(**v))
+                    .max_by_key(|(_, v)| v.len())
+                    .ok_or(Reason::Other("Expected a month in Roman numerals"))?;
+                set(&mut self.month, index as u32 + 1, "month")?;
+                &input[numeral.len()..]
+            }
+            Section::FY => return Err(Reason::Other("The fiscal year can't be read")),
+            Section::SEASON => return Err(Reason::Other("The season can't be read")),
+            Section::MMMMM | Section::DDDDD => {
+                return Err(Reason::Other("Narrow names can't be read"))
+            }
+            Section::D | Section::DD => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.day, value, "day")?;
+                rest
+            }
+            Section::DO => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.day, value, "day")?;
+                rest.strip_prefix(ordinal_suffix(value))
+                    .ok_or(Reason::Expected(ordinal_suffix(value)))?
+            }
+            Section::DDD => {
+                let (value, rest) =
+                    read_name(input, Locale::En.days_abbreviated(), "day of the week")?;
+                set(&mut self.weekday, value, "day of the week")?;
+                rest
+            }
+            Section::DDDD => {
+                let (value, rest) = read_name(input, Locale::En.days_wide(), "day of the week")?;
+                set(&mut self.weekday, value, "day of the week")?;
+                rest
+            }
+            Section::E | Section::EE | Section::EUS => {
+                let (value, rest) =
+                    read_number(input, if *section == Section::EE { 2 } else { 1 })?;
+                if !(1..=7).contains(&value) {
+                    return Err(Reason::Other("Expected a day of the week from 1 to 7"));
+                }
+                let value = match section {
+                    Section::EUS if value == 1 => 7,
+                    Section::EUS => value - 1,
+                    _ => value,
+                };
+                set(&mut self.weekday, value, "day of the week")?;
+                rest
+            }
+            Section::DIM => {
+                let (value, rest) = read_number(input, 2)?;
+                set(
+                    &mut self.days_in_month,
+                    value,
+                    "number of days in the month",
+                )?;
+                rest
+            }
+            Section::NTHDOW => {
+                let (value, rest) = read_number(input, 1)?;
+                set(
+                    &mut self.weekday_of_month,
+                    value,
+                    "occurrence of the day of the week",
+                )?;
+                rest
+            }
+            Section::Q => {
+                let (value, rest) = read_number(input, 1)?;
+                set(&mut self.quarter, value, "quarter")?;
+                rest
+            }
+            Section::QQ => {
+                let input = input.strip_prefix('Q').ok_or(Reason::Expected("Q"))?;
+                let (value, rest) = read_number(input, 1)?;
+                set(&mut self.quarter, value, "quarter")?;
+                rest
+            }
+            Section::HY => {
+                let (value, rest) = read_number(input, 1)?;
+                set(&mut self.half_year, value, "half of the year")?;
+                rest
+            }
+            Section::HYY => {
+                let input = input.strip_prefix('H').ok_or(Reason::Expected("H"))?;
+                let (value, rest) = read_number(input, 1)?;
+                set(&mut self.half_year, value, "half of the year")?;
+                rest
+            }
+            Section::WM => {
+                let (value, rest) = read_number(input, 1)?;
+                set(&mut self.week_of_month, value, "week of the month")?;
+                rest
+            }
+            Section::W | Section::WW => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.iso_week, value, "week")?;
+                rest
+            }
+            Section::ISOYY => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.iso_year_of_century, value, "week-numbering year")?;
+                rest
+            }
+            Section::ISOYYYY => {
+                let (value, rest) = read_signed(input, if adjacent { 4 } else { 9 })?;
+                set(&mut self.iso_year, value as i32, "week-numbering year")?;
+                rest
+            }
+            Section::O | Section::OOO => {
+                let (value, rest) = read_number(input, 3)?;
+                set(&mut self.ordinal, value, "day of the year")?;
+                rest
+            }
+            Section::DOYR => {
+                let (value, rest) = read_number(input, 3)?;
+                set(
+                    &mut self.days_remaining,
+                    value,
+                    "number of days remaining in the year",
+                )?;
+                rest
+            }
+            Section::G => {
+                let (value, rest) = read_either(input, "AD", "BC")?;
+                set(&mut self.is_ad, value, "era")?;
+                rest
+            }
+            Section::GGGG => {
+                let (value, rest) = read_either(input, "Anno Domini", "Before Christ")?;
+                set(&mut self.is_ad, value, "era")?;
+                rest
+            }
+            Section::JD => {
+                let (value, rest) = read_signed(input, 9)?;
+                set(
+                    &mut self.days_from_ce,
+                    value - JULIAN_DAY_OFFSET as i64,
+                    "date",
+                )?;
+                rest
+            }
+            Section::EPOCHDAYS => {
+                let (value, rest) = read_signed(input, 9)?;
+                set(
+                    &mut self.days_from_ce,
+                    value + UNIX_EPOCH_DAYS as i64,
+                    "date",
+                )?;
+                rest
+            }
+            Section::H | Section::HH => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.hour, value, "hour")?;
+                rest
+            }
+            Section::NN => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.minute, value, "minute")?;
+                rest
+            }
+            Section::SS => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.second, value, "second")?;
+                rest
+            }
+            Section::H12 | Section::HH12 => {
+                let (value, rest) = read_number(input, 2)?;
+                set(&mut self.hour12, value, "hour")?;
+                rest
+            }
+            Section::TT | Section::T | Section::AA | Section::A => {
+                let (am, pm) = match section {
+                    Section::TT => ("AM", "PM"),
+                    Section::T => ("A", "P"),
+                    Section::AA => ("am", "pm"),
+                    _ => ("a", "p"),
+                };
+                let (value, rest) = read_either(input, pm, am)?;
+                set(&mut self.is_pm, value, "half of the day")?;
+                rest
+            }
+        };
+
+        Ok(rest)
+    }
+
+    /// Combines the values read into a date, checking that they all agree.
+    fn resolve(self, pivot: YearPivot) -> Result<NaiveDate, FormatError> {
+        let date = match self.days_from_ce {
+            Some(days_from_ce) => {
+                let date = i32::try_from(days_from_ce)
+                    .ok()
+                    .and_then(NaiveDate::from_num_days_from_ce_opt)
+                    .ok_or(FormatError::InvalidDate)?;
+                check(self.year, date.year(), "year")?;
+                check(
+                    self.year_of_century,
+                    date.year().rem_euclid(100) as u32,
+                    "two-digit year",
+                )?;
+                date
+            }
+            None => self.date(pivot)?,
+        };
+
+        let hour = match self.hour12 {
+            Some(hour12) if !(1..=12).contains(&hour12) => return Err(FormatError::InvalidTime),
+            Some(hour12) => {
+                let hour = hour12 % 12 + if self.is_pm == Some(true) { 12 } else { 0 };
+                check(self.hour, hour, "hour")?;
+                Some(hour)
+            }
+            None => self.hour,
+        };
+        if let Some(hour) = hour {
+            check(self.is_pm, hour >= 12, "half of the day")?;
+        }
+        NaiveTime::from_hms_opt(
+            hour.unwrap_or(0),
+            self.minute.unwrap_or(0),
+            self.second.unwrap_or(0),
+        )
+        .ok_or(FormatError::InvalidTime)?;
+
+        check(self.century, century(date.year()), "century")?;
+        check(self.year_hundreds, date.year().div_euclid(100), "century")?;
+        check(self.is_leap_year, is_leap_year(date.year()), "leap year")?;
+        check(self.month, date.month(), "month")?;
+        check(self.day, date.day(), "day")?;
+        check(self.ordinal, date.ordinal(), "day of the year")?;
+        check(self.weekday, weekday_index(&date) as u32, "day of the week")?;
+        check(
+            self.weekday_of_month,
+            weekday_of_month(&date),
+            "occurrence of the day of the week",
+        )?;
+        check(
+            self.days_in_month,
+            days_in_month(&date),
+            "number of days in the month",
+        )?;
+        check(
+            self.days_remaining,
+            days_remaining(&date),
+            "number of days remaining in the year",
+        )?;
+        check(self.quarter, quarter(&date), "quarter")?;
+        check(self.half_year, half_year(&date), "half of the year")?;
+        check(self.iso_week, date.iso_week().week(), "week")?;
+        check(
+            self.week_of_month,
+            WeekConfig::default().week_of_month(&date),
+            "week of the month",
+        )?;
+        check(self.iso_year, date.iso_week().year(), "week-numbering year")?;
+        check(
+            self.iso_year_of_century,
+            date.iso_week().year().rem_euclid(100) as u32,
+            "week-numbering year",
+        )?;
+        check(self.is_ad, date.year() > 0, "era")?;
+
+        Ok(date)
+    }
+
+    /// Builds the date from the year and either the month and the day of the
+    /// month, or the day of the year. Without a year, the date is built from
+    /// the week-numbering year, the week and the day of the week.
+    fn date(&self, pivot: YearPivot) -> Result<NaiveDate, FormatError> {
+        if let (None, None, Some(iso_year)) = (self.year, self.year_of_century, self.iso_year) {
+            let week = self.iso_week.ok_or(FormatError::MissingField("week"))?;
+            let weekday = self
+                .weekday
+                .and_then(|v| Weekday::try_from(v as u8 - 1).ok())
+                .ok_or(FormatError::MissingField("day of the week"))?;
+            return NaiveDate::from_isoywd_opt(iso_year, week, weekday)
+                .ok_or(FormatError::InvalidDate);
+        }
+        let year = match (self.year, self.year_of_century) {
+            (Some(year), Some(year_of_century))
+                if year.rem_euclid(100) as u32 != year_of_century =>
+            {
+                return Err(FormatError::Mismatch("two-digit year"))
+            }
+            (Some(year), _) => year,
+            (None, Some(year_of_century)) => match (self.year_hundreds, self.century) {
+                (Some(year_hundreds), _) => year_hundreds * 100 + year_of_century as i32,
+                // The last year of a century ends with `00`, e.g. 2100.
+                (None, Some(century)) if year_of_century == 0 => century * 100,
+                (None, Some(century)) => (century - 1) * 100 + year_of_century as i32,
+                (None, None) => pivot
+                    .year(year_of_century)
+                    .ok_or(FormatError::InvalidDate)?,
+            },
+            (None, None) => return Err(FormatError::MissingField("year")),
+        };
+        let date = match (self.month, self.day, self.ordinal) {
+            (Some(month), Some(day), _) => {
+                NaiveDate::from_ymd_opt(year, month, day).ok_or(FormatError::InvalidDate)?
+            }
+            (_, _, Some(ordinal)) => {
+                NaiveDate::from_yo_opt(year, ordinal).ok_or(FormatError::InvalidDate)?
+            }
+            (None, _, None) => return Err(FormatError::MissingField("month")),
+            (_, None, None) => return Err(FormatError::MissingField("day")),
+        };
+
+        Ok(date)
+    }
+}
+
+fn set<T: PartialEq>(
+    field: &mut Option<T>,
+    value: T,
+    name: &'static str,
+) -> Result<(), Reason<'static>> {
+    match field {
+        Some(current) if *current != value => Err(Reason::Conflicting(name)),
+        _ => {
+            *field = Some(value);
+            Ok(())
+        }
+    }
+}
+
+fn check<T: PartialEq>(field: Option<T>, actual: T, name: &'static str) -> Result<(), FormatError> {
+    match field {
+        Some(value) if value != actual => Err(FormatError::Mismatch(name)),
+        _ => Ok(()),
+    }
+}
+
+/// Reads up to `max_digits` digits, at least one is required.
+fn read_number(input: &str, max_digits: usize) -> Result<(u32, &str), Reason<'static>> {
+    let len = input
+        .bytes()
+        .take(max_digits)
+        .take_while(u8::is_ascii_digit)
+        .count();
+    if len == 0 {
+        return Err(Reason::Other("Expected a number"));
+    }
+    let value = input[..len]
+        .parse()
+        .map_err(|_| Reason::Other("Number too large"))?;
+
+    Ok((value, &input[len..]))
+}
+
+/// Same as [`read_number`], with an optional leading `-`.
+fn read_signed(input: &str, max_digits: usize) -> Result<(i64, &str), Reason<'static>> {
+    let (negative, digits) = match input.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, input),
+    };
+    let (value, rest) = read_number(digits, max_digits)?;
+    let value = value as i64;
+
+    Ok((if negative { -value } else { value }, rest))
+}
+
+/// Reads the longest of the `names` the input starts with.
+fn read_name<'a>(
+    input: &'a str,
+    names: &phf::OrderedMap<u8, &str>,
+    name: &'static str,
+) -> Result<(u32, &'a str), Reason<'static>> {
+    names
+        .entries()
+        .filter(|(_, v)| input.starts_with(**v))
+        .max_by_key(|(_, v)| v.len())
+        .map(|(&k, v)| (k as u32, &input[v.len()..]))
+        .ok_or(Reason::ExpectedName(name))
+}
+
+/// Reads either `yes` or `no`, returning `true` for the former.
+fn read_either<'a>(
+    input: &'a str,
+    yes: &'static str,
+    no: &'static str,
+) -> Result<(bool, &'a str), Reason<'static>> {
+    if let Some(rest) = input.strip_prefix(yes) {
+        Ok((true, rest))
+    } else if let Some(rest) = input.strip_prefix(no) {
+        Ok((false, rest))
+    } else {
+        Err(Reason::ExpectedEither(yes, no))
+    }
+}
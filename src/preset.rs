@@ -0,0 +1,99 @@
+use std::{
+    collections::HashMap,
+    sync::{OnceLock, RwLock},
+};
+
+use chrono::Datelike;
+
+use crate::{Format, FormatError};
+
+/// Formats referred to by a name, e.g. `short` for `dd/mm/yy`.
+///
+/// [`Presets::new`] starts with the built-in presets:
+///
+/// - `short` `dd/mm/yy`, e.g. `02/03/24`
+/// - `medium` `dd mmm yyyy`, e.g. `02 Mar 2024`
+/// - `long` `dddd, dd mmmm yyyy`, e.g. `Saturday, 02 March 2024`
+/// - `iso_week` `isoyyyy-[W]ww-e`, e.g. `2024-W09-6`
+///
+/// Presets are only available with the `std` feature, which is enabled by
+/// default.
+#[derive(Debug)]
+pub struct Presets {
+    formats: HashMap<String, Format>,
+}
+
+impl Presets {
+    pub fn new() -> Self {
+        let mut presets = Self {
+            formats: HashMap::new(),
+        };
+        for (name, format) in BUILT_IN {
+            presets
+                .register(name, format)
+                .expect("the built-in presets are valid");
+        }
+
+        presets
+    }
+
+    /// Adds the preset `name` for `format`, replacing any preset with the same
+    /// name. Fails if the format is not valid.
+    pub fn register(&mut self, name: &str, format: &str) -> Result<(), FormatError> {
+        self.formats
+            .insert(name.to_string(), Format::parse(format)?);
+
+        Ok(())
+    }
+
+    /// Returns the format of the preset `name`, if there is one.
+    pub fn get(&self, name: &str) -> Option<&Format> {
+        self.formats.get(name)
+    }
+
+    /// Returns a string representing the `date` in the format of the preset
+    /// `name`.
+    pub fn apply(&self, date: &impl Datelike, name: &str) -> Result<String, FormatError> {
+        self.get(name)
+            .map(|v| v.apply(date))
+            .ok_or_else(|| FormatError::UnknownPreset(name.to_string()))
+    }
+}
+
+impl Default for Presets {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+const BUILT_IN: [(&str, &str); 4] = [
+    ("short", "dd/mm/yy"),
+    ("medium", "dd mmm yyyy"),
+    ("long", "dddd, dd mmmm yyyy"),
+    ("iso_week", "isoyyyy-[W]ww-e"),
+];
+
+fn presets() -> &'static RwLock<Presets> {
+    static PRESETS: OnceLock<RwLock<Presets>> = OnceLock::new();
+    PRESETS.get_or_init(|| RwLock::new(Presets::new()))
+}
+
+/// Returns a string representing the `date` in the format of the preset
+/// `name`, which is either built-in (see [`Presets`]) or added with
+/// [`register_preset`].
+pub fn format_preset(date: &impl Datelike, name: &str) -> Result<String, FormatError> {
+    presets()
+        .read()
+        .expect("the presets lock isn't poisoned")
+        .apply(date, name)
+}
+
+/// Adds the preset `name` for `format`, so that it can be used with
+/// [`format_preset`] anywhere in the program. A preset with the same name is
+/// replaced.
+pub fn register_preset(name: &str, format: &str) -> Result<(), FormatError> {
+    presets()
+        .write()
+        .expect("the presets lock isn't poisoned")
+        .register(name, format)
+}
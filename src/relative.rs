@@ -0,0 +1,15 @@
+use alloc::{format, string::String};
+
+use chrono::NaiveDate;
+
+/// Returns how far the `date` is from the `reference` date in English words,
+/// e.g. `today`, `yesterday`, `tomorrow`, `2 days ago` or `in 3 days`.
+pub fn format_relative(date: &NaiveDate, reference: &NaiveDate) -> String {
+    match date.signed_duration_since(*reference).num_days() {
+        0 => "today".into(),
+        -1 => "yesterday".into(),
+        1 => "tomorrow".into(),
+        days if days < 0 => format!("{} days ago", -days),
+        days => format!("in {} days", days),
+    }
+}
@@ -0,0 +1,88 @@
+use alloc::string::{String, ToString};
+use core::fmt::Write;
+
+use chrono::{Datelike, Months, NaiveDate};
+
+use crate::{is_separator, literal_end, starts_literal, unquote, FormatError};
+
+/// Returns a string representing the time between `from` and `to` in the
+/// specified `format`, e.g. `1 year, 2 months, 3 days`. The span is the same
+/// whichever of the two dates comes first.
+///
+/// The format is made of the following counts, along with separators and
+/// literals like in [`format`](fn@crate::format):
+///
+/// - `y` Number of whole years
+/// - `m` Number of whole months after the years
+/// - `d` Number of days after the months
+///
+/// The span is counted on the calendar: the whole months are added to the
+/// earlier date first, and when the day doesn't exist in the month it lands on
+/// the day is moved back to the last day of the month, so from January 31st
+/// to March 1st is `1` month and `1` day in a year that isn't a leap year.
+///
+/// Any letter has to be quoted, e.g. `y 'years', m 'months', d 'days'`.
+pub fn format_span(from: &NaiveDate, to: &NaiveDate, format: &str) -> Result<String, FormatError> {
+    if format.is_empty() {
+        return Err(FormatError::EmptyFormat);
+    }
+    let (years, months, days) = span(*from.min(to), *from.max(to));
+    let mut out = String::new();
+    let mut position = 0;
+    while let Some(c) = format[position..].chars().next() {
+        let end = if starts_literal(c) {
+            let end = literal_end(format, position)?;
+            out.push_str(&unquote(&format[position..end]));
+            end
+        } else {
+            match c {
+                'y' => write!(out, "{}", years),
+                'm' => write!(out, "{}", months),
+                'd' => write!(out, "{}", days),
+                _ if is_separator(c) => out.write_char(c),
+                _ => {
+                    return Err(FormatError::UnknownToken {
+                        token: c.to_string(),
+                        position,
+                        suggestion: None,
+                    })
+                }
+            }
+            .expect("writing to a string doesn't fail");
+            position + c.len_utf8()
+        };
+        position = end;
+    }
+
+    Ok(out)
+}
+
+/// Returns the age on the date `on` of someone born on `birth`, i.e. the number
+/// of whole years between the two dates, or `0` if `on` comes before `birth`.
+///
+/// Like in [`format_span`], someone born on the 29th of February gets one year
+/// older on the 28th of February in the years that aren't leap years.
+pub fn format_age(birth: &NaiveDate, on: &NaiveDate) -> u32 {
+    if on < birth {
+        return 0;
+    }
+
+    span(*birth, *on).0
+}
+
+/// Returns the years, months and days from `from` to the later date `to`.
+fn span(from: NaiveDate, to: NaiveDate) -> (u32, u32, i64) {
+    let mut months =
+        (to.year() * 12 + to.month() as i32) - (from.year() * 12 + from.month() as i32);
+    let shifted = |months: i32| {
+        from.checked_add_months(Months::new(months as u32))
+            .expect("the shifted date is not after to")
+    };
+    if shifted(months) > to {
+        months -= 1;
+    }
+    let days = to.signed_duration_since(shifted(months)).num_days();
+    let months = months as u32;
+
+    (months / 12, months % 12, days)
+}
@@ -0,0 +1,71 @@
+use alloc::{
+    format,
+    string::{String, ToString},
+};
+
+use chrono::Datelike;
+
+use crate::{escape, format, FormatError};
+
+/// Same as [`format`](fn@crate::format), but `pattern` is written with the
+/// specifiers of `strftime`, e.g. `%Y-%m-%d`. The supported specifiers are:
+///
+/// - `%Y` for `yyyy`
+/// - `%y` for `yy`
+/// - `%m` for `mm`
+/// - `%d` for `dd`
+/// - `%b` for `mmm`
+/// - `%B` for `mmmm`
+/// - `%a` for `ddd`
+/// - `%A` for `dddd`
+/// - `%%` for a literal `%`
+///
+/// Any other text is displayed as is.
+pub fn format_strftime_like(date: &impl Datelike, pattern: &str) -> Result<String, FormatError> {
+    format(date, &translate(pattern)?)
+}
+
+/// Turns a `strftime` pattern into the equivalent format, quoting the text
+/// around the specifiers.
+fn translate(pattern: &str) -> Result<String, FormatError> {
+    let mut format = String::new();
+    let mut literal = String::new();
+    let mut chars = pattern.char_indices();
+    while let Some((position, c)) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+        let section = match chars.next().map(|(_, v)| v) {
+            Some('%') => {
+                literal.push('%');
+                continue;
+            }
+            Some('Y') => "yyyy",
+            Some('y') => "yy",
+            Some('m') => "mm",
+            Some('d') => "dd",
+            Some('b') => "mmm",
+            Some('B') => "mmmm",
+            Some('a') => "ddd",
+            Some('A') => "dddd",
+            specifier => {
+                return Err(FormatError::UnknownToken {
+                    token: specifier.map_or("%".to_string(), |v| format!("%{}", v)),
+                    position,
+                    suggestion: None,
+                })
+            }
+        };
+        push_literal(&mut format, &mut literal);
+        format.push_str(section);
+    }
+    push_literal(&mut format, &mut literal);
+
+    Ok(format)
+}
+
+fn push_literal(format: &mut String, literal: &mut String) {
+    format.push_str(&escape(literal));
+    literal.clear();
+}
@@ -0,0 +1,14 @@
+use alloc::string::{String, ToString};
+
+use wasm_bindgen::prelude::*;
+
+use crate::format_ymd;
+
+/// Same as [`format_ymd`], to be called from JavaScript, where a failure is
+/// thrown as an `Error` with the message of the [`FormatError`](crate::FormatError).
+///
+/// Only available with the `wasm` feature.
+#[wasm_bindgen]
+pub fn format_wasm(year: i32, month: u32, day: u32, pattern: &str) -> Result<String, JsValue> {
+    format_ymd(year, month, day, pattern).map_err(|e| JsError::new(&e.to_string()).into())
+}
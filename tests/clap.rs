@@ -0,0 +1,34 @@
+use chrono::NaiveDate;
+use clap::{error::ErrorKind, Arg, Command};
+use tommaso_date_formatter::*;
+
+fn command() -> Command {
+    Command::new("report").arg(
+        Arg::new("format")
+            .long("date-format")
+            .value_parser(clap_parser()),
+    )
+}
+
+#[test]
+fn valid_format_is_parsed() {
+    let matches = command()
+        .try_get_matches_from(["report", "--date-format", "yyyy-mm-dd"])
+        .unwrap();
+    let date = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    assert_eq!(
+        matches.get_one::<Format>("format").unwrap().apply(&date),
+        "2024-03-02"
+    );
+}
+
+#[test]
+fn invalid_format_is_rejected() {
+    let error = command()
+        .try_get_matches_from(["report", "--date-format", "yyyy-xx"])
+        .unwrap_err();
+    assert_eq!(error.kind(), ErrorKind::ValueValidation);
+    assert!(error
+        .to_string()
+        .contains("Part not supported at position 5: \"xx\""));
+}
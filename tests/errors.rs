@@ -0,0 +1,156 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn unknown_token_is_reported_as_such() {
+    assert_eq!(
+        format(&date(2024, 3, 2), "yyyy-xx").unwrap_err(),
+        FormatError::UnknownToken {
+            token: "xx".to_string(),
+            position: 5,
+            suggestion: None,
+        }
+    );
+    assert_eq!(
+        format(&date(2024, 3, 2), "").unwrap_err(),
+        FormatError::EmptyFormat
+    );
+    assert_eq!(
+        parse("2024-03", "yyyy-mm").unwrap_err(),
+        FormatError::MissingField("day")
+    );
+    let error: Box<dyn std::error::Error> = format(&date(2024, 3, 2), "x").unwrap_err().into();
+    assert_eq!(error.to_string(), "Part not supported at position 0: \"x\"");
+}
+
+#[test]
+fn position_of_unknown_token() {
+    assert_eq!(
+        format(&date(2024, 3, 2), "yyyy-xx-dd")
+            .unwrap_err()
+            .to_string(),
+        "Part not supported at position 5: \"xx\""
+    );
+    assert_eq!(
+        format(&date(2024, 3, 2), "dd mm yyyy-xx")
+            .unwrap_err()
+            .to_string(),
+        "Part not supported at position 11: \"xx\""
+    );
+}
+
+#[test]
+fn validate_reports_every_unknown_token() {
+    assert_eq!(
+        validate("xx-mm-zz").unwrap_err(),
+        [
+            FormatError::UnknownToken {
+                token: "xx".to_string(),
+                position: 0,
+                suggestion: None,
+            },
+            FormatError::UnknownToken {
+                token: "zz".to_string(),
+                position: 6,
+                suggestion: None,
+            },
+        ]
+    );
+    assert_eq!(validate("yyyy-mm-dd"), Ok(()));
+    assert_eq!(validate("iso"), Ok(()));
+    assert_eq!(validate("").unwrap_err(), [FormatError::EmptyFormat]);
+}
+
+#[test]
+fn suggestion_for_near_miss() {
+    assert_eq!(
+        format(&date(2024, 3, 2), "yyy-zz").unwrap_err(),
+        FormatError::UnknownToken {
+            token: "yyy".to_string(),
+            position: 0,
+            suggestion: Some("yyyy"),
+        }
+    );
+    assert_eq!(
+        format(&date(2024, 3, 2), "yyyy-zz").unwrap_err(),
+        FormatError::UnknownToken {
+            token: "zz".to_string(),
+            position: 5,
+            suggestion: None,
+        }
+    );
+    assert_eq!(
+        format(&date(2024, 3, 2), "ddd-mmx")
+            .unwrap_err()
+            .to_string(),
+        "Part not supported at position 4: \"mmx\", did you mean \"mmm\"?"
+    );
+}
+
+#[test]
+fn format_without_sections() {
+    let strict = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Format::parse_with("", &strict).unwrap_err(),
+        FormatError::EmptyFormat
+    );
+    assert_eq!(
+        Format::parse_with("/-/", &strict).unwrap_err(),
+        FormatError::NoSection
+    );
+    assert!(Format::parse_with("dd/", &strict).is_ok());
+    assert_eq!(format(&date(2024, 3, 2), "/-/").unwrap(), "/-/");
+}
+
+#[test]
+fn duplicate_fields_rejected() {
+    let reject_duplicates = ParseOptions {
+        reject_duplicates: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Format::parse_with("mm/mmm", &reject_duplicates).unwrap_err(),
+        FormatError::DuplicateField("month")
+    );
+    assert_eq!(
+        Format::parse_with("yyyy-yy", &reject_duplicates).unwrap_err(),
+        FormatError::DuplicateField("year")
+    );
+    assert!(Format::parse_with("dd do", &reject_duplicates).is_err());
+    assert!(Format::parse_with("dddd dd/mm/yyyy", &reject_duplicates).is_ok());
+    assert!(Format::parse_with("/-/", &reject_duplicates).is_ok());
+    assert!(Format::parse("mm/mmm").is_ok());
+    let strict = ParseOptions {
+        strict: true,
+        ..Default::default()
+    };
+    assert!(Format::parse_with("mm/mmm", &strict).is_ok());
+}
+
+#[test]
+fn single_tokens_from_str() {
+    assert_eq!("mmm".parse::<Section>().unwrap(), Section::MMM);
+    assert_eq!("doyr".parse::<Section>().unwrap(), Section::DOYR);
+    assert!(matches!(
+        "xx".parse::<Section>(),
+        Err(FormatError::UnknownToken { position: 0, .. })
+    ));
+    assert!("mm/".parse::<Section>().is_err());
+    assert_eq!(
+        "mmmx".parse::<Section>().unwrap_err(),
+        FormatError::UnknownToken {
+            token: "mmmx".to_string(),
+            position: 0,
+            suggestion: Some("mmmm"),
+        }
+    );
+    assert_eq!("/".parse::<Separator>().unwrap(), Separator::Slash);
+    assert!("x".parse::<Separator>().is_err());
+}
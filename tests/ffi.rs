@@ -0,0 +1,51 @@
+use std::{
+    ffi::{c_char, CStr},
+    ptr,
+};
+
+use tommaso_date_formatter::*;
+
+/// Calls `tdf_format` with a buffer of `len` bytes, returning the code and
+/// what was written to the buffer.
+fn call(year: i32, month: u32, day: u32, pattern: &CStr, len: usize) -> (i32, String) {
+    let mut buffer = vec![1 as c_char; len.max(1)];
+    let code = unsafe { tdf_format(year, month, day, pattern.as_ptr(), buffer.as_mut_ptr(), len) };
+    if len == 0 {
+        return (code, String::new());
+    }
+    let text = unsafe { CStr::from_ptr(buffer.as_ptr()) };
+
+    (code, text.to_str().unwrap().to_string())
+}
+
+#[test]
+fn success() {
+    assert_eq!(
+        call(2024, 3, 2, c"dd/mm/yyyy", 32),
+        (TDF_OK, "02/03/2024".to_string())
+    );
+    assert_eq!(
+        call(2024, 3, 2, c"dd/mm/yyyy", 11),
+        (TDF_OK, "02/03/2024".to_string())
+    );
+}
+
+#[test]
+fn invalid_input() {
+    assert_eq!(call(2024, 3, 2, c"xx", 32).0, TDF_INVALID_FORMAT);
+    assert_eq!(call(2024, 2, 30, c"dd", 32).0, TDF_INVALID_DATE);
+    assert_eq!(call(2024, 3, 2, c"\xff", 32).0, TDF_INVALID_UTF8);
+    let code = unsafe { tdf_format(2024, 3, 2, ptr::null(), ptr::null_mut(), 0) };
+    assert_eq!(code, TDF_NULL_POINTER);
+}
+
+#[test]
+fn buffer_too_small() {
+    assert_eq!(
+        call(2024, 3, 2, c"dd/mm/yyyy", 5),
+        (TDF_TRUNCATED, "02/0".to_string())
+    );
+    assert_eq!(call(2024, 3, 2, c"dd", 0).0, TDF_TRUNCATED);
+    // Only whole characters are written.
+    assert_eq!(call(2024, 3, 2, c"'é'", 2), (TDF_TRUNCATED, String::new()));
+}
@@ -0,0 +1,309 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn parsed_format_applies_to_many_dates() {
+    let format = Format::parse("dd/mm/yyyy").unwrap();
+    let dates = [date(2024, 3, 2), date(1999, 12, 31)];
+    let out: Vec<String> = dates.iter().map(|v| format.apply(v)).collect();
+    assert_eq!(out, ["02/03/2024", "31/12/1999"]);
+    assert_eq!(Format::parse("").unwrap_err(), FormatError::EmptyFormat);
+}
+
+#[test]
+fn format_into_appends() {
+    let mut out = String::from("> ");
+    format_into(&date(2024, 3, 2), "dddd do mmmm yyyy", &mut out).unwrap();
+    assert_eq!(out, "> Saturday 2nd March 2024");
+    Format::parse(" yy")
+        .unwrap()
+        .apply_into(&date(2024, 3, 2), &mut out);
+    assert_eq!(out, "> Saturday 2nd March 2024 24");
+}
+
+#[test]
+fn display_with_format() {
+    let format = Format::parse("dd/mm/yyyy").unwrap();
+    assert_eq!(
+        std::format!("on {}!", date(2024, 3, 2).display_with(&format)),
+        "on 02/03/2024!"
+    );
+    let mut out = String::new();
+    std::fmt::Write::write_fmt(
+        &mut out,
+        format_args!(
+            "[{:>3}]",
+            date(2024, 3, 2).display_with(&Format::parse("d").unwrap())
+        ),
+    )
+    .unwrap();
+    assert_eq!(out, "[2]");
+}
+
+#[test]
+fn parts_are_public() {
+    assert!(enum_iterator::all::<Section>().any(|v| v.value() == "yyyy"));
+    assert_eq!(enum_iterator::all::<Separator>().count(), 8);
+    assert_eq!(Separator::Slash.value(), "/");
+    assert_eq!(FormatPart::Section(Section::MMM).value(), "mmm");
+    assert_eq!(FormatPart::Literal("of".to_string()).value(), "of");
+}
+
+#[test]
+fn catalog_of_tokens() {
+    assert!(supported_tokens()
+        .iter()
+        .any(|(token, description)| *token == "yyyy" && !description.is_empty()));
+    assert_eq!(
+        supported_tokens(),
+        enum_iterator::all::<Section>()
+            .map(|v| (v.value(), v.description()))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(supported_separators()[0], ("/", "Oblique stroke (slash)"));
+    assert_eq!(
+        supported_separators(),
+        enum_iterator::all::<Separator>()
+            .map(|v| (v.value(), v.description()))
+            .collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn display_gives_back_the_pattern() {
+    for pattern in [
+        "yyyy-mm-dd",
+        "dd/mm/yyyy hh:nn",
+        "do [of] mmmm",
+        "d[]o",
+        "mm[]m",
+    ] {
+        assert_eq!(Format::parse(pattern).unwrap().to_string(), pattern);
+    }
+    assert_eq!(
+        Format::parse("do 'of' mmmm").unwrap().to_string(),
+        "do [of] mmmm"
+    );
+}
+
+#[test]
+fn displayed_pattern_is_parsed_back() {
+    for pattern in [
+        "'a'[b]",
+        "d[]o",
+        "'o''clock' hh",
+        "[x]''\\yyyyy",
+        "'[a]'",
+        "''",
+    ] {
+        let format = Format::parse(pattern).unwrap();
+        let displayed = format.to_string();
+        assert_eq!(Format::parse(&displayed).unwrap(), format, "{displayed}");
+        assert_eq!(
+            Format::parse(&displayed).unwrap().apply(&date(2024, 3, 2)),
+            format.apply(&date(2024, 3, 2))
+        );
+    }
+    assert_eq!(Format::parse("'a'[b]").unwrap().to_string(), "[ab]");
+    assert_eq!(
+        Format::parse("d[]o").unwrap().apply(&date(2024, 3, 2)),
+        "262"
+    );
+}
+
+#[test]
+fn builder() {
+    let built = FormatBuilder::new()
+        .year4()
+        .sep_slash()
+        .month2()
+        .sep_slash()
+        .day2()
+        .build();
+    let parsed = Format::parse("yyyy/mm/dd").unwrap();
+    assert_eq!(built, parsed);
+    assert_eq!(
+        built.apply(&date(2024, 3, 2)),
+        parsed.apply(&date(2024, 3, 2))
+    );
+    let built = FormatBuilder::new()
+        .day1()
+        .sep_space()
+        .literal("of")
+        .literal(" ")
+        .month_wide()
+        .build();
+    assert_eq!(built.apply(&date(2024, 3, 2)), "2 of March");
+    assert_eq!(built.to_string(), "d [of ]mmmm");
+    assert_eq!(FormatBuilder::new().build().apply(&date(2024, 3, 2)), "");
+}
+
+#[test]
+fn built_pattern_is_parsed_back() {
+    let built = FormatBuilder::new().day1().section(Section::O).build();
+    assert_eq!(built.apply(&date(2024, 3, 2)), "262");
+    let sources: Vec<&str> = built.parts().map(|v| v.source).collect();
+    assert_eq!(sources, ["d", "o"]);
+    let ranges: Vec<_> = built.parts().map(|v| v.range).collect();
+    assert_eq!(ranges, [0..1, 3..4]);
+    assert_eq!(built.to_string(), "d[]o");
+    assert_eq!(Format::parse(&built.to_string()).unwrap(), built);
+    let built = FormatBuilder::new()
+        .section(Section::CC)
+        .section(Section::YY)
+        .build();
+    assert_eq!(built.to_string(), "cc[]yy");
+}
+
+#[test]
+fn any_datelike() {
+    let datetime = date(2024, 3, 2).and_hms_opt(13, 4, 5).unwrap();
+    assert_eq!(format(&datetime, "dd/mm/yyyy").unwrap(), "02/03/2024");
+    assert_eq!(format(&datetime, "hh").unwrap(), "00");
+    assert_eq!(Format::parse("yy").unwrap().apply(&datetime), "24");
+    let utc = chrono::TimeZone::with_ymd_and_hms(&chrono::Utc, 2024, 3, 2, 1, 2, 3).unwrap();
+    assert_eq!(format(&utc, "dddd").unwrap(), "Saturday");
+}
+
+#[test]
+fn capacity_hint_is_enough() {
+    let format = Format::parse("dddd, do mmmm yyyy 'at' hh:nn tt").unwrap();
+    for month in 1..=12 {
+        let date = date(2024, month, 25);
+        assert!(format.capacity_hint() >= format.apply(&date).len());
+    }
+    assert_eq!(Format::parse("yyyy-mm-dd").unwrap().capacity_hint(), 10);
+}
+
+#[test]
+fn capacity_hint_with_options() {
+    let options = FormatOptions {
+        month_period: true,
+        sign_years: true,
+        ..Default::default()
+    };
+    let format = Format::parse("mmm yyyy").unwrap();
+    assert_eq!(format.capacity_hint(), 8);
+    assert_eq!(format.capacity_hint_with_options(&options), 10);
+    let displayed = format.apply_with_options(&date(2024, 3, 2), &options);
+    assert_eq!(displayed, "Mar. +2024");
+    assert!(format.capacity_hint_with_options(&options) >= displayed.len());
+}
+
+#[test]
+fn render_borrows_names() {
+    use std::borrow::Cow;
+    let format = Format::parse("dddd, dd mmmm yyyy 'at' g mmr").unwrap();
+    let day = date(2024, 3, 2);
+    let pieces: Vec<Cow<str>> = format.render(&day).collect();
+    assert_eq!(pieces.concat(), format.apply(&day));
+    assert!(matches!(pieces[0], Cow::Borrowed("Saturday")));
+    assert!(matches!(pieces[3], Cow::Owned(_)));
+    let datetime = day.and_hms_opt(1, 2, 3).unwrap();
+    assert_eq!(
+        format.render(&datetime).collect::<String>(),
+        format.apply(&datetime)
+    );
+}
+
+#[test]
+fn format_many_is_format_for_each() {
+    let dates = [date(2024, 3, 2), date(1999, 12, 31), date(-44, 3, 15)];
+    let many = format_many(&dates, "dddd dd mmmm yyyy").unwrap();
+    let each: Vec<String> = dates
+        .iter()
+        .map(|v| format(v, "dddd dd mmmm yyyy").unwrap())
+        .collect();
+    assert_eq!(many, each);
+    assert!(format_many(&dates, "xx").is_err());
+    let empty: [NaiveDate; 0] = [];
+    assert!(format_many(&empty, "yyyy").unwrap().is_empty());
+}
+
+#[test]
+fn from_str() {
+    let format: Format = "yyyy-mm-dd".parse().unwrap();
+    assert_eq!(format.apply(&date(2024, 3, 2)), "2024-03-02");
+    assert!("yyyy-xx".parse::<Format>().is_err());
+}
+
+#[test]
+fn override_one_section() {
+    let day = date(2024, 3, 2);
+    let upper = |section, date: &NaiveDate| {
+        (section == Section::MMM).then(|| format(date, "mmm").unwrap().to_uppercase())
+    };
+    assert_eq!(
+        format_with(&day, "dd mmm yyyy", upper).unwrap(),
+        "02 MAR 2024"
+    );
+    assert_eq!(
+        format_with(&day, "[mmm] dd", |_, _| None).unwrap(),
+        "mmm 02"
+    );
+    assert!(format_with(&day, "xx", |_, _| None).is_err());
+}
+
+#[test]
+fn sections_of_format() {
+    let format = Format::parse("mm/yyyy").unwrap();
+    assert!(format.contains_section(Section::MM));
+    assert!(format.contains_section(Section::YYYY));
+    assert!(!format.contains_section(Section::DD));
+    assert_eq!(
+        Format::parse("mm/yyyy mm").unwrap().sections(),
+        vec![Section::MM, Section::YYYY]
+    );
+}
+
+#[test]
+fn constant_output_is_borrowed() {
+    use std::borrow::Cow;
+    let format = Format::parse("--/ [at] ''").unwrap();
+    let output = format.render_cow(&date(2024, 3, 2));
+    assert!(matches!(output, Cow::Borrowed("--/ at '")));
+    assert_eq!(output, format.render_cow(&date(1999, 1, 1)));
+    let format = Format::parse("dd/mm").unwrap();
+    assert!(matches!(
+        format.render_cow(&date(2024, 3, 2)),
+        Cow::Owned(ref v) if v == "02/03"
+    ));
+    let built = FormatBuilder::new().sep_slash().literal("x").build();
+    assert!(matches!(
+        built.render_cow(&date(2024, 3, 2)),
+        Cow::Borrowed("/x")
+    ));
+}
+
+#[test]
+fn format_from_year_month_day() {
+    assert_eq!(format_ymd(2024, 3, 2, "dd/mm/yyyy").unwrap(), "02/03/2024");
+    assert_eq!(
+        format_ymd(2024, 2, 30, "dd/mm/yyyy").unwrap_err(),
+        FormatError::InvalidDate
+    );
+    assert!(matches!(
+        format_ymd(2024, 3, 2, "xx").unwrap_err(),
+        FormatError::UnknownToken { .. }
+    ));
+}
+
+#[test]
+fn format_or_none() {
+    assert_eq!(
+        format_opt(&date(2024, 3, 2), "dd/mm/yyyy"),
+        Some("02/03/2024".to_string())
+    );
+    assert_eq!(format_opt(&date(2024, 3, 2), "xx"), None);
+}
+
+#[test]
+fn default_format() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format_default(&day), format(&day, DEFAULT_FORMAT).unwrap());
+    assert_eq!(format_default(&day), "2024-03-02");
+}
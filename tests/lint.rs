@@ -0,0 +1,46 @@
+use tommaso_date_formatter::*;
+
+#[test]
+fn adjacent_numbers() {
+    assert_eq!(
+        lint("yyyymmdd"),
+        vec![
+            Lint::AdjacentNumbers { position: 4 },
+            Lint::AdjacentNumbers { position: 6 },
+        ]
+    );
+    assert!(lint("dd/mm/yyyy").is_empty());
+}
+
+#[test]
+fn two_digit_year() {
+    assert_eq!(lint("dd/mm/yy"), vec![Lint::TwoDigitYear { position: 6 }]);
+}
+
+#[test]
+fn month_next_to_hours() {
+    let lints = lint("hh:mm");
+    assert_eq!(lints, vec![Lint::MonthLooksLikeMinutes { position: 3 }]);
+    assert!(!lints[0].to_string().is_empty());
+    assert!(lint("hh:nn").is_empty());
+}
+
+#[test]
+fn ordinal_day_after_number() {
+    assert_eq!(lint("mmdo"), vec![Lint::AdjacentNumbers { position: 2 }]);
+    assert_eq!(lint("ddo"), vec![Lint::AdjacentNumbers { position: 2 }]);
+    assert!(lint("mm do").is_empty());
+}
+
+#[test]
+fn invalid_format() {
+    let lints = lint("xx");
+    assert_eq!(
+        lints,
+        vec![Lint::InvalidPattern(Format::parse("xx").unwrap_err())]
+    );
+    assert_eq!(
+        lints[0].to_string(),
+        "The format is not valid: Part not supported at position 0: \"xx\""
+    );
+}
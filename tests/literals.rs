@@ -0,0 +1,110 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn quoted_literals() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format(&day, "do 'of' mmmm").unwrap(), "2nd of March");
+    assert_eq!(format(&day, "''yy").unwrap(), "'24");
+    assert_eq!(format(&day, "'o''clock'").unwrap(), "o'clock");
+    assert_eq!(format(&day, "'a/b.c' yyyy").unwrap(), "a/b.c 2024");
+    assert_eq!(format(&day, "'yyyy'yyyy").unwrap(), "yyyy2024");
+    assert_eq!(format(&day, "'é'dd'ü'").unwrap(), "é02ü");
+    assert_eq!(
+        format(&day, "yyyy 'oops").unwrap_err(),
+        FormatError::UnterminatedLiteral { position: 5 }
+    );
+    assert_eq!(
+        parse("2nd of March 2024", "do 'of' mmmm yyyy").unwrap(),
+        day
+    );
+}
+
+#[test]
+fn bracket_literals() {
+    let day = date(2024, 1, 30);
+    assert_eq!(format(&day, "[Week] ww").unwrap(), "Week 05");
+    assert_eq!(format(&day, "[a/b.c [d]-dd").unwrap(), "a/b.c [d-30");
+    assert_eq!(format(&day, "[[[Week] ww").unwrap(), "[Week 05");
+    assert_eq!(format(&day, "[[yyyy").unwrap(), "[2024");
+    assert_eq!(
+        format(&day, "ww [x").unwrap_err(),
+        FormatError::UnterminatedLiteral { position: 3 }
+    );
+}
+
+#[test]
+fn unknown_text_as_literal() {
+    let options = ParseOptions {
+        allow_literals: true,
+        ..Default::default()
+    };
+    let apply = |pattern| {
+        Format::parse_with(pattern, &options)
+            .unwrap()
+            .apply(&date(2024, 3, 2))
+    };
+    assert_eq!(apply("d° mmmm"), "2° March");
+    assert_eq!(apply("yyyy年m月d号"), "2024年3月2号");
+    assert_eq!(apply("xyyyyx"), "x2024x");
+    assert!(Format::parse("d° mmmm").is_err());
+}
+
+#[test]
+fn escaped_text() {
+    let day = date(2024, 3, 2);
+    for text in [
+        "yyyy", "ddmm", "it's", "'", "''", "[x]", "[[", "]]", "a]b[c", "\\d",
+    ] {
+        let pattern = format!("{} yyyy", escape(text));
+        assert_eq!(
+            format(&day, &pattern).unwrap(),
+            text.to_string() + " 2024",
+            "{pattern}"
+        );
+    }
+    assert_eq!(escape("ddmm"), "[ddmm]");
+    assert_eq!(escape(""), "");
+}
+
+#[test]
+fn escaped_texts_stay_apart() {
+    let day = date(2024, 3, 2);
+    let pattern = escape("a") + &escape("b");
+    assert_eq!(pattern, "[a][b]");
+    assert_eq!(format(&day, &pattern).unwrap(), "ab");
+    let pattern = escape("'") + &escape("'");
+    assert_eq!(format(&day, &pattern).unwrap(), "''");
+}
+
+#[test]
+fn backslash_escapes() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format(&day, "\\[yyyy\\]").unwrap(), "[2024]");
+    assert_eq!(format(&day, r"dd\.mm").unwrap(), "02.03");
+    assert_eq!(format(&day, r"\d\d dd").unwrap(), "dd 02");
+    assert_eq!(format(&day, r"\\ yy").unwrap(), "\\ 24");
+    assert_eq!(format(&day, r"yy\é").unwrap(), "24é");
+    let kinds: Vec<_> = Format::parse(r"dd\.mm")
+        .unwrap()
+        .parts()
+        .map(|v| v.kind)
+        .collect();
+    assert_eq!(
+        kinds,
+        vec![PartKind::Section, PartKind::Literal, PartKind::Section]
+    );
+    assert_eq!(
+        Format::parse(r"yy\").unwrap_err(),
+        FormatError::UnterminatedLiteral { position: 2 }
+    );
+    assert_eq!(Format::parse(r"dd\.mm").unwrap().to_string(), "dd[.]mm");
+    assert_eq!(
+        format_span(&date(2024, 1, 1), &day, r"d \d").unwrap(),
+        "1 d"
+    );
+}
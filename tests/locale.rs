@@ -0,0 +1,117 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn names_in_another_language() {
+    assert_eq!(
+        format_localized(&date(2024, 3, 2), "mmmm", Locale::It).unwrap(),
+        "marzo"
+    );
+    assert_eq!(
+        format_localized(&date(2024, 3, 1), "dddd", Locale::Fr).unwrap(),
+        "vendredi"
+    );
+    assert_eq!(
+        format_localized(&date(2024, 3, 1), "ddd d mmm", Locale::De).unwrap(),
+        "Fr. 1 März"
+    );
+    assert_eq!(
+        format_localized(&date(2024, 3, 1), "dddd mmmm", Locale::En).unwrap(),
+        "Friday March"
+    );
+}
+
+struct Uppercase;
+
+impl NameProvider for Uppercase {
+    fn month_wide(&self, m: u8) -> &str {
+        ["JANUARY", "FEBRUARY", "MARCH"][(m as usize - 1) % 3]
+    }
+
+    fn month_abbrev(&self, m: u8) -> &str {
+        ["JAN", "FEB", "MAR"][(m as usize - 1) % 3]
+    }
+
+    fn weekday_wide(&self, d: u8) -> &str {
+        [
+            "MONDAY",
+            "TUESDAY",
+            "WEDNESDAY",
+            "THURSDAY",
+            "FRIDAY",
+            "SATURDAY",
+            "SUNDAY",
+        ][d as usize - 1]
+    }
+
+    fn weekday_abbrev(&self, d: u8) -> &str {
+        ["MON", "TUE", "WED", "THU", "FRI", "SAT", "SUN"][d as usize - 1]
+    }
+}
+
+#[test]
+fn names_from_provider() {
+    assert_eq!(
+        format_with_provider(&date(2024, 3, 2), "dddd ddd mmmm mmm", &Uppercase).unwrap(),
+        "SATURDAY SAT MARCH MAR"
+    );
+    assert_eq!(
+        format_with_provider(&date(2024, 3, 2), "dddd mmmm", &EnglishNames).unwrap(),
+        "Saturday March"
+    );
+    assert_eq!(
+        format_with_provider(&date(2024, 3, 2), "mmmm", &Locale::It).unwrap(),
+        "marzo"
+    );
+}
+
+#[test]
+fn ordinal_suffix_of_locale() {
+    let first = date(2024, 3, 1);
+    assert_eq!(format_localized(&first, "do", Locale::Fr).unwrap(), "1er");
+    assert_eq!(
+        format_localized(&date(2024, 3, 2), "do", Locale::Fr).unwrap(),
+        "2e"
+    );
+    assert_eq!(format_localized(&first, "do", Locale::It).unwrap(), "1º");
+    assert_eq!(format_localized(&first, "do", Locale::De).unwrap(), "1.");
+    assert_eq!(format_localized(&first, "do", Locale::En).unwrap(), "1st");
+    assert_eq!(format(&date(2024, 3, 2), "do").unwrap(), "2nd");
+}
+
+struct Trimestre;
+
+impl NameProvider for Trimestre {
+    fn month_wide(&self, month: u8) -> &str {
+        Locale::En.month_wide(month)
+    }
+
+    fn month_abbrev(&self, month: u8) -> &str {
+        Locale::En.month_abbrev(month)
+    }
+
+    fn weekday_wide(&self, weekday: u8) -> &str {
+        Locale::En.weekday_wide(weekday)
+    }
+
+    fn weekday_abbrev(&self, weekday: u8) -> &str {
+        Locale::En.weekday_abbrev(weekday)
+    }
+
+    fn quarter_label(&self) -> &str {
+        "T"
+    }
+}
+
+#[test]
+fn quarter_label() {
+    let day = date(2024, 5, 2);
+    assert_eq!(format(&day, "qq").unwrap(), "Q2");
+    assert_eq!(format_with_provider(&day, "qq", &Trimestre).unwrap(), "T2");
+    assert_eq!(format_localized(&day, "qq", Locale::It).unwrap(), "T2");
+    assert_eq!(format_localized(&day, "qq", Locale::De).unwrap(), "Q2");
+}
@@ -0,0 +1,17 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn date_format_is_format() {
+    let day = date(2024, 3, 2);
+    assert_eq!(
+        date_format!(day, "yyyy-mm-dd").unwrap(),
+        format(&day, "yyyy-mm-dd").unwrap()
+    );
+    assert_eq!(date_format!(date(2024, 3, 2), "dd",).unwrap(), "02");
+    assert!(date_format!(day, "xx").is_err());
+}
@@ -0,0 +1,37 @@
+use chrono::Weekday;
+use tommaso_date_formatter::*;
+
+#[test]
+fn weekday_headers_from_first_day() {
+    assert_eq!(
+        weekday_headers(NameStyle::Narrow, Weekday::Sun),
+        ["S", "M", "T", "W", "T", "F", "S"]
+    );
+    let headers = weekday_headers(NameStyle::Abbreviated, Weekday::Mon);
+    assert_eq!(headers[0], "Mon");
+    assert_eq!(headers[6], "Sun");
+    assert_eq!(weekday_headers(NameStyle::Wide, Weekday::Sat)[1], "Sunday");
+}
+
+#[test]
+fn month_names_in_each_style() {
+    let names = month_names(NameStyle::Wide, Locale::En);
+    assert_eq!(names[1], "February");
+    assert_eq!(names[11], "December");
+    assert_eq!(month_names(NameStyle::Abbreviated, Locale::It)[4], "mag");
+    assert_eq!(month_names(NameStyle::Narrow, Locale::De)[0], "J");
+}
+
+#[test]
+fn name_of_one_field() {
+    let date = chrono::NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    assert_eq!(weekday_name(&date, NameStyle::Wide, Locale::En), "Saturday");
+    assert_eq!(
+        weekday_name(&date, NameStyle::Abbreviated, Locale::En),
+        "Sat"
+    );
+    assert_eq!(month_name(&date, NameStyle::Wide, Locale::En), "March");
+    assert_eq!(month_name(&date, NameStyle::Abbreviated, Locale::En), "Mar");
+    assert_eq!(weekday_name(&date, NameStyle::Wide, Locale::It), "sabato");
+    assert_eq!(month_name(&date, NameStyle::Wide, Locale::De), "März");
+}
@@ -0,0 +1,28 @@
+//! Checks that the crate works without the `std` feature, with
+//! `cargo test --no-default-features --test no_std`: this file only uses
+//! `core` and `alloc`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::ToString;
+
+use chrono::NaiveDate;
+use tommaso_date_formatter::{format, parse, supported_tokens, Format, FormatError};
+
+#[test]
+fn format_without_std() {
+    let date = NaiveDate::from_ymd_opt(2024, 3, 2).unwrap();
+    assert_eq!(
+        format(&date, "dddd, do mmmm yyyy").unwrap(),
+        "Saturday, 2nd March 2024"
+    );
+    assert_eq!(parse("2024-03-02", "yyyy-mm-dd").unwrap(), date);
+    assert_eq!(Format::parse("yyyymmdd").unwrap().apply(&date), "20240302");
+    assert!(!supported_tokens().is_empty());
+    assert_eq!(
+        format(&date, "yyyy-xx").unwrap_err().to_string(),
+        "Part not supported at position 5: \"xx\""
+    );
+    assert_eq!(format(&date, "").unwrap_err(), FormatError::EmptyFormat);
+}
@@ -0,0 +1,261 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn case_of_names() {
+    let with = |case| FormatOptions {
+        case,
+        ..Default::default()
+    };
+    let day = date(2024, 3, 2);
+    assert_eq!(
+        format_with_options(&day, "mmmm dd", &with(OutputCase::Upper)).unwrap(),
+        "MARCH 02"
+    );
+    assert_eq!(
+        format_with_options(&day, "mmmm ddd", &with(OutputCase::Lower)).unwrap(),
+        "march sat"
+    );
+    assert_eq!(
+        format_with_options(&day, "mmmm", &with(OutputCase::AsIs)).unwrap(),
+        "March"
+    );
+    assert_eq!(
+        format_with_options(&day, "mmmm", &FormatOptions::default()).unwrap(),
+        "March"
+    );
+    assert_eq!(
+        format_with_options(&day, "dd mm yyyy", &with(OutputCase::Upper)).unwrap(),
+        "02 03 2024"
+    );
+    assert_eq!(
+        format_with_options(&day, "dddd", &with(OutputCase::Title)).unwrap(),
+        "Saturday"
+    );
+}
+
+#[test]
+fn padding() {
+    let with = |pad| FormatOptions {
+        pad,
+        ..Default::default()
+    };
+    let day = date(2024, 3, 2);
+    assert_eq!(
+        format_with_options(&day, "dd/mm/yy", &with(PadStyle::Space)).unwrap(),
+        " 2/ 3/24"
+    );
+    assert_eq!(
+        format_with_options(&day, "dd", &with(PadStyle::Zero)).unwrap(),
+        "02"
+    );
+    assert_eq!(
+        format_with_options(&day, "dd mm", &with(PadStyle::None)).unwrap(),
+        "2 3"
+    );
+    assert_eq!(format(&date(2007, 3, 2), "dd mm yy").unwrap(), "02 03 07");
+}
+
+#[test]
+fn first_day_of_week() {
+    let iso = FormatOptions::default();
+    let sunday = FormatOptions {
+        week: WeekConfig {
+            first_day: chrono::Weekday::Sun,
+        },
+        ..Default::default()
+    };
+    assert_eq!(
+        format_with_options(&date(2024, 1, 7), "ww e", &iso).unwrap(),
+        "01 7"
+    );
+    assert_eq!(
+        format_with_options(&date(2024, 1, 7), "ww e", &sunday).unwrap(),
+        "02 1"
+    );
+    assert_eq!(
+        format_with_options(&date(2024, 1, 6), "w e", &sunday).unwrap(),
+        "1 7"
+    );
+    assert_eq!(
+        format_with_options(&date(2023, 1, 1), "w", &sunday).unwrap(),
+        "1"
+    );
+    assert_eq!(
+        format_with_options(&date(2023, 1, 1), "w", &iso).unwrap(),
+        "52"
+    );
+}
+
+#[test]
+fn case_insensitive_tokens() {
+    let options = ParseOptions {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    let format = Format::parse_with("YYYY-MM-DD MMMM [X]", &options).unwrap();
+    assert_eq!(format.apply(&date(2024, 3, 2)), "2024-03-02 March X");
+    assert_eq!(format.parts().next().unwrap().source, "YYYY");
+    assert!(Format::parse("YYYY-MM-DD").is_err());
+    assert_eq!(
+        Format::parse_with("Mmdd", &options)
+            .unwrap()
+            .apply(&date(2024, 3, 2)),
+        "0302"
+    );
+}
+
+#[test]
+fn fiscal_year() {
+    let july = FormatOptions {
+        fiscal_start: 7,
+        ..Default::default()
+    };
+    assert_eq!(
+        format_with_options(&date(2024, 6, 30), "[FY]fy", &july).unwrap(),
+        "FY2024"
+    );
+    assert_eq!(
+        format_with_options(&date(2024, 7, 1), "[FY]fy", &july).unwrap(),
+        "FY2025"
+    );
+    assert_eq!(format(&date(2024, 7, 1), "fy").unwrap(), "2024");
+    assert_eq!(format(&date(2024, 12, 31), "fy").unwrap(), "2024");
+}
+
+#[test]
+fn digit_styles() {
+    let format = Format::parse("yyyy-mm-dd [Q1] qq").unwrap();
+    let day = date(2024, 3, 2);
+    let with = |digits| FormatOptions {
+        digits,
+        ..Default::default()
+    };
+    assert_eq!(
+        format.apply_with_options(&day, &with(DigitStyle::ArabicIndic)),
+        "٢٠٢٤-٠٣-٠٢ Q1 Q١"
+    );
+    assert_eq!(
+        format.apply_with_options(&day, &with(DigitStyle::Devanagari)),
+        "२०२४-०३-०२ Q1 Q१"
+    );
+    assert_eq!(
+        format.apply_with_options(&day, &with(DigitStyle::Ascii)),
+        "2024-03-02 Q1 Q1"
+    );
+    assert_eq!(FormatOptions::default().digits, DigitStyle::Ascii);
+}
+
+#[test]
+fn season_of_hemisphere() {
+    let south = FormatOptions {
+        hemisphere: Hemisphere::Southern,
+        ..Default::default()
+    };
+    assert_eq!(format(&date(2024, 3, 2), "season").unwrap(), "Spring");
+    assert_eq!(
+        format_with_options(&date(2024, 3, 2), "season", &south).unwrap(),
+        "Autumn"
+    );
+    let seasons: Vec<String> = (1..=12)
+        .map(|v| format(&date(2024, v, 1), "season").unwrap())
+        .collect();
+    assert_eq!(seasons[0], "Winter");
+    assert_eq!(seasons[5], "Summer");
+    assert_eq!(seasons[8], "Autumn");
+    assert_eq!(seasons[11], "Winter");
+    assert_eq!(
+        format_with_options(&date(2024, 12, 1), "season", &south).unwrap(),
+        "Summer"
+    );
+    assert!(parse("Spring 2024", "season yyyy").is_err());
+}
+
+#[test]
+fn period_after_abbreviated_month() {
+    let period = FormatOptions {
+        month_period: true,
+        ..Default::default()
+    };
+    assert_eq!(format(&date(2024, 3, 2), "dd mmm").unwrap(), "02 Mar");
+    assert_eq!(
+        format_with_options(&date(2024, 3, 2), "dd mmm", &period).unwrap(),
+        "02 Mar."
+    );
+    // May is not abbreviated.
+    assert_eq!(
+        format_with_options(&date(2024, 5, 2), "dd mmm", &period).unwrap(),
+        "02 May"
+    );
+    let upper = FormatOptions {
+        case: OutputCase::Upper,
+        ..period
+    };
+    assert_eq!(
+        Format::parse("mmm")
+            .unwrap()
+            .apply_with_options(&date(2024, 3, 2), &upper),
+        "MAR."
+    );
+}
+
+#[test]
+fn week_of_month() {
+    assert_eq!(format(&date(2024, 3, 1), "wm").unwrap(), "1");
+    // March 2024 starts on a Friday: weeks from Monday are 1-3, 4-10, ..., 25-31.
+    assert_eq!(format(&date(2024, 3, 3), "wm").unwrap(), "1");
+    assert_eq!(format(&date(2024, 3, 4), "wm").unwrap(), "2");
+    assert_eq!(format(&date(2024, 3, 31), "wm").unwrap(), "5");
+    let sunday = FormatOptions {
+        week: WeekConfig {
+            first_day: chrono::Weekday::Sun,
+        },
+        ..Default::default()
+    };
+    // From Sunday: 1-2, 3-9, ..., 24-30, 31.
+    assert_eq!(
+        format_with_options(&date(2024, 3, 3), "wm", &sunday).unwrap(),
+        "2"
+    );
+    assert_eq!(
+        format_with_options(&date(2024, 3, 31), "wm", &sunday).unwrap(),
+        "6"
+    );
+    assert_eq!(
+        parse("2024-03-31 5", "yyyy-mm-dd wm").unwrap(),
+        date(2024, 3, 31)
+    );
+    assert!(parse("2024-03-31 4", "yyyy-mm-dd wm").is_err());
+}
+
+#[test]
+fn signed_years() {
+    let signed = FormatOptions {
+        sign_years: true,
+        ..Default::default()
+    };
+    let format = Format::parse("yyyy").unwrap();
+    assert_eq!(format.apply(&date(2024, 3, 2)), "2024");
+    assert_eq!(format.apply(&date(-44, 3, 15)), "-0044");
+    assert_eq!(
+        format.apply_with_options(&date(2024, 3, 2), &signed),
+        "+2024"
+    );
+    assert_eq!(
+        format.apply_with_options(&date(-44, 3, 15), &signed),
+        "-0044"
+    );
+    assert_eq!(format.apply_with_options(&date(0, 1, 1), &signed), "+0000");
+    assert_eq!(
+        format_with_options(&date(2024, 3, 2), "yyyyy fy", &signed).unwrap(),
+        "+02024 2024"
+    );
+    assert_eq!(
+        parse("+2024-03-02", "yyyy-mm-dd").unwrap(),
+        date(2024, 3, 2)
+    );
+}
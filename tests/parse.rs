@@ -0,0 +1,111 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn formatted_dates_are_read_back() {
+    for pattern in [
+        "yyyy-mm-dd",
+        "dd mmm yyyy",
+        "yyyymmdd",
+        "dddd do mmmm yyyy",
+        "ddd d.m.yy",
+        "yyyy ooo",
+        "g yyyy mmmm dd qq ww",
+    ] {
+        for day in [date(2024, 3, 2), date(1999, 12, 31), date(2021, 1, 1)] {
+            let text = format(&day, pattern).unwrap();
+            assert_eq!(parse(&text, pattern).unwrap(), day, "{pattern}: {text}");
+        }
+    }
+    assert_eq!(parse("-44-03-15", "yyyy-mm-dd").unwrap(), date(-44, 3, 15));
+    assert_eq!(parse("85-01-01", "yy-mm-dd").unwrap(), date(1985, 1, 1));
+}
+
+#[test]
+fn reading_fails_clearly() {
+    assert_eq!(
+        parse("2024-03", "yyyy-mm").unwrap_err().to_string(),
+        "The format doesn't contain the day"
+    );
+    assert_eq!(
+        parse("85", "yy").unwrap_err().to_string(),
+        "The format doesn't contain the month"
+    );
+    assert_eq!(
+        parse("Sun 02/03/2024", "ddd dd/mm/yyyy")
+            .unwrap_err()
+            .to_string(),
+        "The day of the week doesn't match the date"
+    );
+    assert_eq!(
+        parse("2024/03/02", "yyyy-mm-dd").unwrap_err().to_string(),
+        "Expected \"-\" at position 4"
+    );
+    assert_eq!(
+        parse("2024-13-02", "yyyy-mm-dd").unwrap_err(),
+        FormatError::InvalidDate
+    );
+    assert_eq!(
+        parse("2024-03-02x", "yyyy-mm-dd").unwrap_err().to_string(),
+        "Unexpected input at position 10"
+    );
+}
+
+#[test]
+fn try_parse_gives_none_on_mismatch() {
+    assert_eq!(try_parse("2024/13/40", "yyyy/mm/dd"), None);
+    assert_eq!(try_parse("2024-03-02", "yyyy/mm/dd"), None);
+    assert_eq!(try_parse("2024/02/30", "yyyy/mm/dd"), None);
+    assert_eq!(try_parse("2024/03/02", "yyyy/xx"), None);
+    assert_eq!(
+        try_parse("2024/03/02", "yyyy/mm/dd"),
+        Some(date(2024, 3, 2))
+    );
+    let format = Format::parse("do mmmm yyyy").unwrap();
+    assert_eq!(
+        format.try_parse_date("2nd March 2024"),
+        Some(date(2024, 3, 2))
+    );
+    assert_eq!(format.try_parse_date("2th March 2024"), None);
+}
+
+#[test]
+fn two_digit_year_pivot() {
+    assert_eq!(parse("24-03-02", "yy-mm-dd").unwrap(), date(2024, 3, 2));
+    assert_eq!(parse("85-03-02", "yy-mm-dd").unwrap(), date(1985, 3, 2));
+    let pivot = YearPivot { first_year: 1990 };
+    assert_eq!(
+        parse_with_pivot("85-03-02", "yy-mm-dd", pivot).unwrap(),
+        date(2085, 3, 2)
+    );
+    assert_eq!(
+        parse_with_pivot("90-03-02", "yy-mm-dd", pivot).unwrap(),
+        date(1990, 3, 2)
+    );
+    let pivot = YearPivot { first_year: -150 };
+    assert_eq!(
+        parse_with_pivot("60-03-02", "yy-mm-dd", pivot).unwrap(),
+        date(-140, 3, 2)
+    );
+    for first_year in [i32::MAX, i32::MIN] {
+        let pivot = YearPivot { first_year };
+        assert_eq!(
+            parse_with_pivot("99-03-02", "yy-mm-dd", pivot),
+            Err(FormatError::InvalidDate)
+        );
+    }
+}
+
+#[test]
+fn round_trip() {
+    let day = date(2024, 3, 2);
+    assert!(roundtrip_ok(&day, "yyyy-mm-dd"));
+    assert!(roundtrip_ok(&day, "dddd, do mmmm yyyy"));
+    assert!(!roundtrip_ok(&day, "mmm"));
+    assert!(!roundtrip_ok(&date(1924, 3, 2), "dd/mm/yy"));
+    assert!(!roundtrip_ok(&day, "xx"));
+}
@@ -0,0 +1,30 @@
+use tommaso_date_formatter::*;
+
+#[test]
+fn ranges_of_parts() {
+    let format = Format::parse("yyyy-mm").unwrap();
+    let parts: Vec<PartInfo> = format.parts().collect();
+    assert_eq!(parts.len(), 3);
+    assert_eq!(parts[0].range, 0..4);
+    assert_eq!(parts[1].range, 4..5);
+    assert_eq!(parts[2].range, 5..7);
+    assert_eq!(parts[1].kind, PartKind::Separator);
+    assert_eq!(parts[2].source, "mm");
+}
+
+#[test]
+fn kinds_of_parts() {
+    let format = Format::parse("dd 'of' [x]mmmm").unwrap();
+    let parts: Vec<_> = format.parts().map(|v| (v.kind, v.source)).collect();
+    assert_eq!(
+        parts,
+        vec![
+            (PartKind::Section, "dd"),
+            (PartKind::Separator, " "),
+            (PartKind::Literal, "'of'"),
+            (PartKind::Separator, " "),
+            (PartKind::Literal, "[x]"),
+            (PartKind::Section, "mmmm"),
+        ]
+    );
+}
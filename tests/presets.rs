@@ -0,0 +1,88 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn iso_keyword() {
+    assert_eq!(format_iso(&date(987, 1, 2)), "0987-01-02");
+    assert_eq!(
+        format_iso(&date(-44, 3, 15)),
+        format(&date(-44, 3, 15), "yyyy-mm-dd").unwrap()
+    );
+    assert_eq!(format(&date(987, 1, 2), "iso").unwrap(), "0987-01-02");
+    assert_eq!(format(&date(2024, 3, 2), "iso").unwrap(), "2024-03-02");
+    assert_eq!(parse("0987-01-02", "iso").unwrap(), date(987, 1, 2));
+    assert_eq!(
+        Format::parse("iso").unwrap(),
+        Format::parse("yyyy-mm-dd").unwrap()
+    );
+}
+
+#[test]
+fn parts_of_iso_keyword_refer_to_it() {
+    let pattern = "iso";
+    let format = Format::parse(pattern).unwrap();
+    assert_eq!(format.parts().count(), 5);
+    for part in format.parts() {
+        assert_eq!(part.range, 0..3);
+        assert_eq!(&pattern[part.range], "iso");
+        assert_eq!(part.source, "iso");
+    }
+    assert_eq!(lint(pattern), []);
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn built_in_presets() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format_preset(&day, "short").unwrap(), "02/03/24");
+    assert_eq!(format_preset(&day, "medium").unwrap(), "02 Mar 2024");
+    assert_eq!(
+        format_preset(&day, "long").unwrap(),
+        "Saturday, 02 March 2024"
+    );
+    assert_eq!(
+        format_preset(&day, "nope").unwrap_err(),
+        FormatError::UnknownPreset("nope".to_string())
+    );
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn registered_presets() {
+    register_preset("compact", "yyyymmdd").unwrap();
+    assert_eq!(
+        format_preset(&date(2024, 3, 2), "compact").unwrap(),
+        "20240302"
+    );
+    assert!(register_preset("bad", "xx").is_err());
+    let mut presets = Presets::new();
+    presets.register("year", "yy").unwrap();
+    assert_eq!(presets.apply(&date(2024, 3, 2), "year").unwrap(), "24");
+    assert!(presets.get("short").is_some());
+}
+
+#[test]
+fn iso_week_date() {
+    assert_eq!(format_iso_week(&date(2024, 3, 9)), "2024-W10-6");
+    assert_eq!(format_iso_week(&date(2021, 1, 1)), "2020-W53-5");
+    assert_eq!(format_iso_week(&date(2019, 12, 30)), "2020-W01-1");
+    for day in [date(2024, 3, 9), date(2021, 1, 1), date(-44, 3, 15)] {
+        assert_eq!(
+            format_iso_week(&day),
+            format(&day, "isoyyyy-[W]ww-e").unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "std")]
+#[test]
+fn iso_week_preset() {
+    assert_eq!(
+        format_preset(&date(2024, 3, 9), "iso_week").unwrap(),
+        "2024-W10-6"
+    );
+}
@@ -0,0 +1,63 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn relative_to_reference() {
+    let reference = date(2024, 3, 2);
+    assert_eq!(format_relative(&reference, &reference), "today");
+    assert_eq!(format_relative(&date(2024, 3, 1), &reference), "yesterday");
+    assert_eq!(format_relative(&date(2024, 3, 3), &reference), "tomorrow");
+    assert_eq!(
+        format_relative(&date(2024, 2, 21), &reference),
+        "10 days ago"
+    );
+    assert_eq!(
+        format_relative(&date(2024, 3, 12), &reference),
+        "in 10 days"
+    );
+}
+
+#[test]
+fn span_between_dates() {
+    let pattern = "y 'years', m 'months', d 'days'";
+    assert_eq!(
+        format_span(&date(2023, 1, 15), &date(2024, 3, 18), pattern).unwrap(),
+        "1 years, 2 months, 3 days"
+    );
+    assert_eq!(
+        format_span(&date(2024, 3, 18), &date(2023, 1, 15), pattern).unwrap(),
+        "1 years, 2 months, 3 days"
+    );
+    let today = date(2024, 3, 2);
+    assert_eq!(format_span(&today, &today, "d 'days'").unwrap(), "0 days");
+    let end_of_january = date(2023, 1, 31);
+    assert_eq!(
+        format_span(&end_of_january, &date(2023, 3, 1), "m/d").unwrap(),
+        "1/1"
+    );
+    assert_eq!(
+        format_span(&end_of_january, &date(2023, 2, 28), "m/d").unwrap(),
+        "1/0"
+    );
+    assert!(format_span(&end_of_january, &date(2023, 3, 1), "x").is_err());
+    assert!(format_span(&end_of_january, &date(2023, 3, 1), "").is_err());
+}
+
+#[test]
+fn age_in_completed_years() {
+    let birth = date(1990, 6, 15);
+    assert_eq!(format_age(&birth, &date(2024, 6, 14)), 33);
+    assert_eq!(format_age(&birth, &date(2024, 6, 15)), 34);
+    assert_eq!(format_age(&birth, &date(2024, 6, 16)), 34);
+    assert_eq!(format_age(&birth, &date(1980, 1, 1)), 0);
+    // Born on the 29th of February, a year older on the 28th in other years.
+    let leap = date(2000, 2, 29);
+    assert_eq!(format_age(&leap, &date(2023, 2, 27)), 22);
+    assert_eq!(format_age(&leap, &date(2023, 2, 28)), 23);
+    assert_eq!(format_age(&leap, &date(2024, 2, 28)), 23);
+    assert_eq!(format_age(&leap, &date(2024, 2, 29)), 24);
+}
@@ -0,0 +1,410 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn full_month_name() {
+    assert_eq!(
+        format(&date(2024, 3, 2), "dd mmmm yyyy").unwrap(),
+        "02 March 2024"
+    );
+    assert_eq!(format(&date(2024, 9, 30), "mmmm").unwrap(), "September");
+}
+
+#[test]
+fn abbreviated_weekday() {
+    assert_eq!(format(&date(2024, 3, 1), "ddd").unwrap(), "Fri");
+    assert_eq!(format(&date(2024, 3, 4), "ddd").unwrap(), "Mon");
+    assert_eq!(format(&date(2024, 3, 3), "ddd").unwrap(), "Sun");
+}
+
+#[test]
+fn full_weekday() {
+    assert_eq!(
+        format(&date(2024, 3, 2), "dddd, dd mmmm yyyy").unwrap(),
+        "Saturday, 02 March 2024"
+    );
+    assert_eq!(format(&date(2024, 3, 3), "dddd").unwrap(), "Sunday");
+    assert_eq!(
+        format(&date(2024, 3, 4), "dddd ddd dd d").unwrap(),
+        "Monday Mon 04 4"
+    );
+}
+
+#[test]
+fn day_with_ordinal_suffix() {
+    assert_eq!(format(&date(2024, 3, 22), "do mmm").unwrap(), "22nd Mar");
+    for (day, expected) in [
+        (1, "1st"),
+        (2, "2nd"),
+        (3, "3rd"),
+        (4, "4th"),
+        (11, "11th"),
+        (12, "12th"),
+        (13, "13th"),
+        (21, "21st"),
+        (22, "22nd"),
+        (23, "23rd"),
+        (31, "31st"),
+    ] {
+        assert_eq!(format(&date(2024, 3, day), "do").unwrap(), expected);
+    }
+}
+
+#[test]
+fn quarter() {
+    assert_eq!(format(&date(2024, 1, 2), "q qq").unwrap(), "1 Q1");
+    assert_eq!(format(&date(2024, 3, 31), "q").unwrap(), "1");
+    assert_eq!(format(&date(2024, 4, 1), "q").unwrap(), "2");
+    assert_eq!(format(&date(2024, 9, 30), "q").unwrap(), "3");
+    assert_eq!(format(&date(2024, 12, 2), "q qq").unwrap(), "4 Q4");
+}
+
+#[test]
+fn iso_week() {
+    assert_eq!(format(&date(2021, 1, 1), "ww").unwrap(), "53");
+    assert_eq!(format(&date(2020, 12, 28), "w").unwrap(), "53");
+    assert_eq!(format(&date(2024, 6, 15), "ww w").unwrap(), "24 24");
+    assert_eq!(format(&date(2024, 1, 3), "ww w").unwrap(), "01 1");
+}
+
+#[test]
+fn day_of_year() {
+    assert_eq!(format(&date(2024, 12, 31), "o").unwrap(), "366");
+    assert_eq!(format(&date(2023, 12, 31), "o").unwrap(), "365");
+    assert_eq!(format(&date(2024, 1, 1), "o ooo").unwrap(), "1 001");
+    assert_eq!(format(&date(2024, 3, 1), "ooo").unwrap(), "061");
+}
+
+#[test]
+fn era() {
+    assert_eq!(
+        format(&date(2024, 3, 15), "g gggg").unwrap(),
+        "AD Anno Domini"
+    );
+    assert_eq!(
+        format(&date(-44, 3, 15), "g gggg").unwrap(),
+        "BC Before Christ"
+    );
+    assert_eq!(format(&date(1, 1, 1), "g").unwrap(), "AD");
+    assert_eq!(format(&date(0, 12, 31), "g").unwrap(), "BC");
+}
+
+#[test]
+fn adjacent_sections() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format(&day, "yyyymmdd").unwrap(), "20240302");
+    assert_eq!(format(&day, "ddmmyy").unwrap(), "020324");
+    assert_eq!(format(&day, "yyyymm-dd").unwrap(), "202403-02");
+    assert_eq!(format(&day, "yyyy-mm-dd").unwrap(), "2024-03-02");
+    assert_eq!(format(&day, "mmmmdddd").unwrap(), "MarchSaturday");
+    assert_eq!(
+        format(&day, "yyyy-xx-dd").unwrap_err().to_string(),
+        "Part not supported at position 5: \"xx\""
+    );
+}
+
+struct Accented;
+
+impl NameProvider for Accented {
+    fn month_wide(&self, _: u8) -> &str {
+        "Élan"
+    }
+
+    fn month_abbrev(&self, _: u8) -> &str {
+        "Él"
+    }
+
+    fn weekday_wide(&self, _: u8) -> &str {
+        "Ûn"
+    }
+
+    fn weekday_abbrev(&self, _: u8) -> &str {
+        "Û"
+    }
+}
+
+#[test]
+fn narrow_names() {
+    assert_eq!(format(&date(2024, 3, 2), "mmmmm ddddd").unwrap(), "M S");
+    assert_eq!(format(&date(2024, 6, 2), "mmmmm").unwrap(), "J");
+    assert_eq!(format(&date(2024, 7, 2), "mmmmm").unwrap(), "J");
+    assert_eq!(
+        format_localized(&date(2024, 3, 7), "ddddd", Locale::It).unwrap(),
+        "G"
+    );
+    assert_eq!(
+        format_with_provider(&date(2024, 3, 7), "mmmmm ddddd", &Accented).unwrap(),
+        "É Û"
+    );
+    assert!(parse("M", "mmmmm").is_err());
+}
+
+#[test]
+fn two_digit_year() {
+    assert_eq!(format(&date(1900, 3, 2), "yy").unwrap(), "00");
+    assert_eq!(format(&date(2005, 3, 2), "yy").unwrap(), "05");
+    assert_eq!(format(&date(7, 3, 2), "yy").unwrap(), "07");
+    assert_eq!(format(&date(-44, 3, 2), "yy").unwrap(), "56");
+}
+
+#[test]
+fn four_digit_year() {
+    assert_eq!(format(&date(5, 3, 2), "yyyy").unwrap(), "0005");
+    assert_eq!(format(&date(987, 3, 2), "yyyy").unwrap(), "0987");
+    assert_eq!(format(&date(2024, 3, 2), "yyyy").unwrap(), "2024");
+    assert_eq!(format(&date(12345, 3, 2), "yyyy").unwrap(), "12345");
+    assert_eq!(format(&date(-44, 3, 2), "yyyy").unwrap(), "-0044");
+    assert_eq!(format(&date(-12345, 3, 2), "yyyy").unwrap(), "-12345");
+}
+
+#[test]
+fn weekday_number() {
+    // Monday is 1 for `e`, Sunday is 1 for `eus`.
+    assert_eq!(format(&date(2024, 3, 4), "e ee eus").unwrap(), "1 01 2");
+    assert_eq!(format(&date(2024, 3, 3), "e ee eus").unwrap(), "7 07 1");
+    assert_eq!(
+        parse("2024-03-03 7 1", "yyyy-mm-dd e eus").unwrap(),
+        date(2024, 3, 3)
+    );
+    assert!(parse("2024-03-03 2", "yyyy-mm-dd eus").is_err());
+    assert!(parse("2024-03-03 8", "yyyy-mm-dd e").is_err());
+}
+
+#[test]
+fn julian_day() {
+    assert_eq!(format(&date(2000, 1, 1), "jd").unwrap(), "2451545");
+    assert_eq!(format(&date(-4713, 11, 24), "jd").unwrap(), "0");
+    assert_eq!(format(&date(-4713, 11, 23), "jd").unwrap(), "-1");
+    assert_eq!(parse("2451545", "jd").unwrap(), date(2000, 1, 1));
+    assert_eq!(parse("-1", "jd").unwrap(), date(-4713, 11, 23));
+    assert_eq!(
+        parse("2000 2451545 Sat", "yyyy jd ddd").unwrap(),
+        date(2000, 1, 1)
+    );
+    assert!(parse("2001 2451545", "yyyy jd").is_err());
+}
+
+#[test]
+fn days_since_1970() {
+    assert_eq!(format(&date(1970, 1, 1), "epochdays").unwrap(), "0");
+    assert_eq!(format(&date(1970, 1, 2), "epochdays").unwrap(), "1");
+    assert_eq!(format(&date(1969, 12, 31), "epochdays").unwrap(), "-1");
+    assert_eq!(format(&date(2024, 3, 2), "epochdays").unwrap(), "19784");
+    assert_eq!(parse("-1", "epochdays").unwrap(), date(1969, 12, 31));
+    assert_eq!(
+        parse("2440588 0", "jd epochdays").unwrap(),
+        date(1970, 1, 1)
+    );
+}
+
+#[test]
+fn occurrence_of_weekday() {
+    assert_eq!(format(&date(2024, 3, 12), "nthdow ddd").unwrap(), "2 Tue");
+    assert_eq!(format(&date(2024, 7, 1), "nthdow").unwrap(), "1");
+    assert_eq!(format(&date(2024, 3, 31), "nthdow").unwrap(), "5");
+    assert!(parse("2024-03-12 2", "yyyy-mm-dd nthdow").is_ok());
+    assert!(parse("2024-03-12 3", "yyyy-mm-dd nthdow").is_err());
+}
+
+#[test]
+fn days_in_month() {
+    assert_eq!(format(&date(2024, 2, 10), "dim").unwrap(), "29");
+    assert_eq!(format(&date(2023, 2, 10), "dim").unwrap(), "28");
+    assert_eq!(format(&date(2023, 4, 10), "dim").unwrap(), "30");
+    assert_eq!(format(&date(2023, 12, 10), "dim").unwrap(), "31");
+    assert_eq!(format(&NaiveDate::MAX, "dim").unwrap(), "31");
+    assert!(parse("2024-02-01 28", "yyyy-mm-dd dim").is_err());
+}
+
+#[test]
+fn leap_year() {
+    assert_eq!(format(&date(2024, 2, 10), "leap").unwrap(), "true");
+    assert_eq!(format(&date(2023, 2, 10), "leap").unwrap(), "false");
+    assert_eq!(format(&date(1900, 2, 10), "leap").unwrap(), "false");
+    assert_eq!(format(&date(2000, 2, 10), "leap").unwrap(), "true");
+    assert!(parse("2024-02-01 false", "yyyy-mm-dd leap").is_err());
+    assert!(parse("2024-02-01 true", "yyyy-mm-dd leap").is_ok());
+}
+
+#[test]
+fn roman_month() {
+    assert_eq!(format(&date(2024, 3, 10), "mmr").unwrap(), "III");
+    assert_eq!(format(&date(2024, 12, 10), "mmr").unwrap(), "XII");
+    assert_eq!(format(&date(2024, 11, 10), "mmr").unwrap(), "XI");
+    assert_eq!(
+        parse("10.VIII.2024", "dd.mmr.yyyy").unwrap(),
+        date(2024, 8, 10)
+    );
+    assert_eq!(
+        parse("10.IV.2024", "d.mmr.yyyy").unwrap(),
+        date(2024, 4, 10)
+    );
+    assert_eq!(
+        parse("10.XII.2024", "d.mmr.yyyy").unwrap(),
+        date(2024, 12, 10)
+    );
+}
+
+#[test]
+fn signed_year() {
+    assert_eq!(format(&date(-43, 3, 10), "yyyyy").unwrap(), "-00043");
+    assert_eq!(format(&date(2024, 3, 10), "yyyyy").unwrap(), "+02024");
+    assert_eq!(format(&date(100000, 3, 10), "yyyyy").unwrap(), "+100000");
+    assert_eq!(
+        parse("+02024-03-10", "yyyyy-mm-dd").unwrap(),
+        date(2024, 3, 10)
+    );
+    assert_eq!(
+        parse("-00043-03-10", "yyyyy-mm-dd").unwrap(),
+        date(-43, 3, 10)
+    );
+    assert!(parse("02024-03-10", "yyyyy-mm-dd").is_err());
+}
+
+#[test]
+fn half_year() {
+    assert_eq!(format(&date(2024, 6, 30), "hy hyy").unwrap(), "1 H1");
+    assert_eq!(format(&date(2024, 7, 1), "hy hyy").unwrap(), "2 H2");
+    assert!(parse("2024-07-01 H2", "yyyy-mm-dd hyy").is_ok());
+    assert!(parse("2024-07-01 1", "yyyy-mm-dd hy").is_err());
+}
+
+#[test]
+fn century() {
+    assert_eq!(format(&date(1999, 1, 1), "cc c").unwrap(), "20 19");
+    assert_eq!(format(&date(2000, 1, 1), "cc c").unwrap(), "20 20");
+    assert_eq!(format(&date(2001, 1, 1), "cc c").unwrap(), "21 20");
+    assert_eq!(format(&date(2024, 3, 2), "cc c").unwrap(), "21 20");
+    assert!(parse("21 2000-01-01", "cc yyyy-mm-dd").is_err());
+    assert!(parse("20 2000-01-01", "cc yyyy-mm-dd").is_ok());
+}
+
+#[test]
+fn century_next_to_year() {
+    assert_eq!(format(&date(2024, 3, 2), "cyy").unwrap(), "2024");
+    assert_eq!(parse("2024-03-02", "cyy-mm-dd").unwrap(), date(2024, 3, 2));
+    assert_eq!(parse("1824-01-01", "cyy-mm-dd").unwrap(), date(1824, 1, 1));
+    assert_eq!(
+        format(&date(2024, 3, 2), "ccyy").unwrap_err(),
+        FormatError::AmbiguousCentury { position: 0 }
+    );
+    assert_eq!(
+        validate("yyyy ccyy").unwrap_err(),
+        [FormatError::AmbiguousCentury { position: 5 }]
+    );
+    assert_eq!(format(&date(2024, 3, 2), "cc[]yy").unwrap(), "2124");
+    assert_eq!(
+        parse("21 00 03 02", "cc yy mm dd").unwrap(),
+        date(2100, 3, 2)
+    );
+    assert_eq!(
+        parse("21 24 03 02", "cc yy mm dd").unwrap(),
+        date(2024, 3, 2)
+    );
+    let built = FormatBuilder::new()
+        .section(Section::CC)
+        .section(Section::YY)
+        .build();
+    assert_eq!(built.apply(&date(2024, 3, 2)), "2124");
+}
+
+#[test]
+fn run_of_sections_split_into_sections() {
+    assert_eq!(format(&date(2024, 3, 2), "mmmr").unwrap(), "3III");
+}
+
+#[test]
+fn week_numbering_year() {
+    assert_eq!(format(&date(2021, 1, 1), "isoyyyy").unwrap(), "2020");
+    assert_eq!(format(&date(2021, 1, 1), "yyyy").unwrap(), "2021");
+    assert_eq!(
+        format(&date(2019, 12, 30), "isoyyyy isoyy").unwrap(),
+        "2020 20"
+    );
+    assert_eq!(
+        format(&date(2021, 1, 1), "isoyyyy-[W]ww").unwrap(),
+        "2020-W53"
+    );
+    assert_eq!(
+        parse("2020-W53-5", "isoyyyy-[W]ww-e").unwrap(),
+        date(2021, 1, 1)
+    );
+    assert!(parse("2021-01-01 2021", "yyyy-mm-dd isoyyyy").is_err());
+    assert!(parse("2021-01-01 21", "yyyy-mm-dd isoyy").is_err());
+    assert!(parse("2020-W53", "isoyyyy-[W]ww").is_err());
+}
+
+#[test]
+fn week_numbering_year_next_to_era() {
+    assert_eq!(
+        format(&date(2021, 1, 1), "g gggg yyyy isoyyyy").unwrap(),
+        "AD Anno Domini 2021 2020"
+    );
+    assert_eq!(
+        format(&date(2021, 1, 1), "ggggisoyy").unwrap(),
+        "Anno Domini20"
+    );
+}
+
+#[test]
+fn weekday_names_from_monday() {
+    let names = [
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+        "Sunday",
+    ];
+    for (index, name) in names.iter().enumerate() {
+        // The 4th of March 2024 is a Monday.
+        let day = date(2024, 3, 4 + index as u32);
+        assert_eq!(format(&day, "dddd").unwrap(), *name);
+        assert_eq!(format(&day, "ddd").unwrap(), &name[..3]);
+        assert_eq!(format(&day, "ddddd").unwrap(), &name[..1]);
+        assert_eq!(format(&day, "e").unwrap(), (index + 1).to_string());
+        let text = format(&day, "dddd dd/mm/yyyy").unwrap();
+        assert_eq!(parse(&text, "dddd dd/mm/yyyy").unwrap(), day);
+    }
+}
+
+#[test]
+fn extreme_dates() {
+    let pattern = "yyyy yyyyy mm dd";
+    assert_eq!(
+        format(&NaiveDate::MIN, pattern).unwrap(),
+        "-262143 -262143 01 01"
+    );
+    assert_eq!(
+        format(&NaiveDate::MAX, pattern).unwrap(),
+        "262142 +262142 12 31"
+    );
+    assert_eq!(format(&date(-1, 12, 31), "fy").unwrap(), "-0001");
+    assert_eq!(
+        format(&date(10191, 1, 1), "yyyy yyyyy fy").unwrap(),
+        "10191 +10191 10191"
+    );
+    for day in [NaiveDate::MIN, NaiveDate::MAX] {
+        let text = format(&day, "yyyy-mm-dd").unwrap();
+        assert_eq!(parse(&text, "yyyy-mm-dd").unwrap(), day);
+    }
+}
+
+#[test]
+fn days_remaining_in_year() {
+    assert_eq!(format(&date(2024, 12, 31), "doyr").unwrap(), "0");
+    assert_eq!(format(&date(2024, 1, 1), "doyr").unwrap(), "365");
+    assert_eq!(format(&date(2023, 1, 1), "doyr").unwrap(), "364");
+    assert_eq!(format(&date(2024, 3, 1), "doyr").unwrap(), "305");
+    assert_eq!(format(&date(2024, 3, 1), "doyy").unwrap(), "1st24");
+    assert_eq!(
+        parse("2024-03-01 305", "yyyy-mm-dd doyr").unwrap(),
+        date(2024, 3, 1)
+    );
+    assert!(parse("2024-03-01 304", "yyyy-mm-dd doyr").is_err());
+}
@@ -0,0 +1,64 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn colon() {
+    let datetime = date(2024, 3, 2).and_hms_opt(9, 5, 7).unwrap();
+    assert_eq!(
+        format_datetime(&datetime, "yyyy-mm-dd hh:nn:ss").unwrap(),
+        "2024-03-02 09:05:07"
+    );
+    assert_eq!(format(&date(2024, 3, 2), ":").unwrap(), ":");
+    assert_eq!(
+        parse("2024-03-02 09:05", "yyyy-mm-dd hh:nn").unwrap(),
+        date(2024, 3, 2)
+    );
+}
+
+#[test]
+fn comma() {
+    assert_eq!(
+        format(&date(2024, 3, 2), "dddd, dd mmmm").unwrap(),
+        "Saturday, 02 March"
+    );
+    assert_eq!(
+        format(&date(2024, 3, 2), "ddd, mmm d").unwrap(),
+        "Sat, Mar 2"
+    );
+    assert_eq!(
+        Format::parse(", ")
+            .unwrap()
+            .parts()
+            .map(|v| v.kind)
+            .collect::<Vec<_>>(),
+        [PartKind::Separator, PartKind::Separator]
+    );
+}
+
+#[test]
+fn runs_of_separators() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format(&day, "dd -- mm").unwrap(), "02 -- 03");
+    assert_eq!(format(&day, "dd . / mm").unwrap(), "02 . / 03");
+    assert_eq!(format(&day, "  dd::mm,, ").unwrap(), "  02::03,, ");
+}
+
+#[test]
+fn underscore_and_at() {
+    let day = date(2024, 3, 2);
+    assert_eq!(format(&day, "yyyy_mm_dd").unwrap(), "2024_03_02");
+    assert_eq!(format(&day, "yyyy@ww").unwrap(), "2024@09");
+    assert_eq!(format(&day, "yyyy_@_mm").unwrap(), "2024_@_03");
+    let built = FormatBuilder::new()
+        .year4()
+        .sep_underscore()
+        .month2()
+        .sep_at()
+        .day2()
+        .build();
+    assert_eq!(built.apply(&day), "2024_03@02");
+}
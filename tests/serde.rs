@@ -0,0 +1,51 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn round_trip(format: &Format) -> Format {
+    serde_json::from_str(&serde_json::to_string(format).unwrap()).unwrap()
+}
+
+#[test]
+fn serialized_as_pattern() {
+    let format = Format::parse("dd/mm/yyyy").unwrap();
+    assert_eq!(serde_json::to_string(&format).unwrap(), "\"dd/mm/yyyy\"");
+    assert_eq!(round_trip(&format), format);
+}
+
+#[test]
+fn round_trip_keeps_parts() {
+    let case_insensitive = ParseOptions {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    let allow_literals = ParseOptions {
+        allow_literals: true,
+        ..Default::default()
+    };
+    let formats = [
+        Format::parse_with("YYYY-MM-DD", &case_insensitive).unwrap(),
+        Format::parse_with("d° mmmm", &allow_literals).unwrap(),
+        Format::parse("iso").unwrap(),
+        FormatBuilder::new().day1().section(Section::O).build(),
+    ];
+    for format in formats {
+        let read = round_trip(&format);
+        assert_eq!(read, format);
+        assert_eq!(
+            read.apply(&date(2024, 3, 2)),
+            format.apply(&date(2024, 3, 2))
+        );
+    }
+}
+
+#[test]
+fn invalid_pattern_fails() {
+    let error = serde_json::from_str::<Format>("\"yyyy-xx\"").unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("Part not supported at position 5: \"xx\""));
+}
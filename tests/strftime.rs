@@ -0,0 +1,30 @@
+use chrono::NaiveDate;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn same_as_native_pattern() {
+    let day = date(2024, 3, 2);
+    assert_eq!(
+        format_strftime_like(&day, "%A, %d %B %Y").unwrap(),
+        format(&day, "dddd, dd mmmm yyyy").unwrap()
+    );
+    assert_eq!(format_strftime_like(&day, "%a %b").unwrap(), "Sat Mar");
+    assert_eq!(
+        format_strftime_like(&day, "%y%m%d 100%% it's").unwrap(),
+        "240302 100% it's"
+    );
+}
+
+#[test]
+fn unknown_specifier() {
+    let day = date(2024, 3, 2);
+    assert_eq!(
+        format_strftime_like(&day, "%Y %Q").unwrap_err().to_string(),
+        "Part not supported at position 3: \"%Q\""
+    );
+    assert!(format_strftime_like(&day, "%Y%").is_err());
+}
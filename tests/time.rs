@@ -0,0 +1,56 @@
+use chrono::{NaiveDate, NaiveDateTime};
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+fn at(hour: u32, minute: u32, second: u32) -> NaiveDateTime {
+    date(2024, 3, 2).and_hms_opt(hour, minute, second).unwrap()
+}
+
+#[test]
+fn time_sections() {
+    assert_eq!(
+        format_datetime(&at(9, 5, 7), "yyyy-mm-dd hh'.'nn'.'ss h").unwrap(),
+        "2024-03-02 09.05.07 9"
+    );
+    assert_eq!(format(&date(2024, 3, 2), "hh nn ss").unwrap(), "00 00 00");
+    let format = Format::parse("hh").unwrap();
+    assert_eq!(at(9, 5, 7).display_with(&format).to_string(), "09");
+    assert_eq!(
+        parse("2024-03-02 09", "yyyy-mm-dd hh").unwrap(),
+        date(2024, 3, 2)
+    );
+    assert_eq!(
+        parse("2024-03-02 29", "yyyy-mm-dd hh").unwrap_err(),
+        FormatError::InvalidTime
+    );
+}
+
+#[test]
+fn twelve_hour_clock() {
+    assert_eq!(format_datetime(&at(0, 0, 0), "h12 tt").unwrap(), "12 AM");
+    assert_eq!(format_datetime(&at(12, 0, 0), "h12 tt").unwrap(), "12 PM");
+    assert_eq!(format_datetime(&at(23, 0, 0), "h12 tt").unwrap(), "11 PM");
+    assert_eq!(
+        format_datetime(&at(13, 0, 0), "hh12 t aa a").unwrap(),
+        "01 P pm p"
+    );
+    assert_eq!(
+        format_datetime(&at(9, 0, 0), "hh12 t aa a").unwrap(),
+        "09 A am a"
+    );
+    assert_eq!(
+        parse("2024-03-02 11 PM", "yyyy-mm-dd h12 tt").unwrap(),
+        date(2024, 3, 2)
+    );
+    assert_eq!(
+        parse("2024-03-02 13 11 PM", "yyyy-mm-dd hh h12 tt").unwrap_err(),
+        FormatError::Mismatch("hour")
+    );
+    assert_eq!(
+        parse("2024-03-02 13 AM", "yyyy-mm-dd hh tt").unwrap_err(),
+        FormatError::Mismatch("half of the day")
+    );
+}
@@ -0,0 +1,97 @@
+use chrono::NaiveDate;
+use enum_iterator::all;
+use tommaso_date_formatter::*;
+
+fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(year, month, day).unwrap()
+}
+
+#[test]
+fn every_token_is_found() {
+    for section in all::<Section>() {
+        let format = Format::parse(section.value()).unwrap();
+        assert_eq!(
+            format.parts().map(|v| v.source).collect::<Vec<_>>(),
+            [section.value()]
+        );
+        assert!(format.contains_section(section));
+    }
+    for separator in all::<Separator>() {
+        let format = Format::parse(&format!("yy{}", separator.value())).unwrap();
+        assert_eq!(
+            format.apply(&date(2024, 3, 2)),
+            format!("24{}", separator.value())
+        );
+    }
+    assert!(Format::parse("YYYY").is_err());
+}
+
+#[test]
+fn longest_token_is_taken() {
+    let day = date(2024, 3, 2);
+    assert_eq!(
+        format(&day, "yyyyymmmmdddd").unwrap(),
+        "+02024MarchSaturday"
+    );
+    assert_eq!(format(&day, "epochdaysdd").unwrap(), "1978402");
+    assert_eq!(format(&day, "hh12").unwrap(), "12");
+    let options = ParseOptions {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Format::parse_with("EPOCHDAYS DoMMMM", &options)
+            .unwrap()
+            .apply(&day),
+        "19784 2ndMarch"
+    );
+}
+
+#[test]
+fn single_pass_gives_the_same_parts() {
+    let day = date(2024, 3, 2);
+    let literals = ParseOptions {
+        allow_literals: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Format::parse_with("d° mmmm", &literals)
+            .unwrap()
+            .apply(&day),
+        "2° March"
+    );
+    let case_insensitive = ParseOptions {
+        case_insensitive: true,
+        ..Default::default()
+    };
+    assert_eq!(
+        Format::parse_with("YYYYmmDD", &case_insensitive)
+            .unwrap()
+            .apply(&day),
+        "20240302"
+    );
+    let ranges: Vec<_> = Format::parse("yyyymmdd [x]")
+        .unwrap()
+        .parts()
+        .map(|v| v.range)
+        .collect();
+    assert_eq!(ranges, vec![0..4, 4..6, 6..8, 8..9, 9..12]);
+}
+
+#[test]
+fn single_pass_gives_the_same_errors() {
+    assert_eq!(
+        Format::parse("yyyy-xmm").unwrap_err(),
+        FormatError::UnknownToken {
+            token: "xmm".into(),
+            position: 5,
+            suggestion: Some("mmm")
+        }
+    );
+    assert_eq!(validate("xx/yyyy/zz").unwrap_err().len(), 2);
+    assert_eq!(
+        validate("xx/'abc").unwrap_err(),
+        vec![FormatError::UnterminatedLiteral { position: 3 }]
+    );
+    assert_eq!(validate("").unwrap_err(), vec![FormatError::EmptyFormat]);
+}
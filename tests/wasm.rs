@@ -0,0 +1,9 @@
+//! Only the successful calls can run outside of WebAssembly, since errors are
+//! turned into JavaScript values.
+
+use tommaso_date_formatter::*;
+
+#[test]
+fn format_for_javascript() {
+    assert_eq!(format_wasm(2024, 3, 2, "dd/mm/yyyy").unwrap(), "02/03/2024");
+}